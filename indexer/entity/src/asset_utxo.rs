@@ -0,0 +1,46 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "AssetUtxo")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub utxo_id: i64,
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub asset_id: i64,
+    // u64 according to spec, which doesn't fit in a BIGINT
+    #[sea_orm(column_type = "Decimal(Some((20, 0)))")]
+    pub amount: Decimal,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction_output::Entity",
+        from = "Column::UtxoId",
+        to = "super::transaction_output::Column::Id"
+    )]
+    TransactionOutput,
+    #[sea_orm(
+        belongs_to = "super::native_asset::Entity",
+        from = "Column::AssetId",
+        to = "super::native_asset::Column::Id"
+    )]
+    NativeAsset,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_output::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionOutput.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::native_asset::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NativeAsset.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
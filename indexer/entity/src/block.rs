@@ -11,6 +11,15 @@ pub struct Model {
     pub height: i32,
     pub epoch: i32,
     pub slot: i32,
+    // header details (null for blocks added before these columns existed until backfilled)
+    pub pool_id: Option<Vec<u8>>, // hash of the issuer vkey (null for Byron)
+    pub vrf_output: Option<Vec<u8>>,
+    pub protocol_major: Option<i32>,
+    pub protocol_minor: Option<i32>,
+    pub body_size: Option<i32>,
+    pub tx_count: Option<i32>,
+    #[sea_orm(column_type = "BigInteger", nullable)]
+    pub time: Option<i64>, // unix timestamp (milliseconds) of the slot
 }
 
 #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
@@ -21,7 +30,7 @@ pub enum Relation {
 
 impl ActiveModelBehavior for ActiveModel {}
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum EraValue {
     Byron,
     Shelley,
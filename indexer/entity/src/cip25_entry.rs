@@ -12,6 +12,13 @@ pub struct Model {
     pub asset_id: i64,
     pub payload: Vec<u8>,
     pub version: String,
+    // standard fields decoded from the payload
+    pub name: Option<String>,
+    pub image: Option<String>,
+    pub media_type: Option<String>,
+    pub description: Option<String>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub files: Option<Json>,
 }
 
 #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
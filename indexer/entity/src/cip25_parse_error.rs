@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Cip25ParseError")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub metadata_id: i64,
+    // raw keys instead of a NativeAsset reference since rejected entries may not match any asset
+    pub policy_id: Option<Vec<u8>>,
+    pub asset_name: Option<Vec<u8>>,
+    pub reason: String,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction_metadata::Entity",
+        from = "Column::MetadataId",
+        to = "super::transaction_metadata::Column::Id"
+    )]
+    TransactionMetadata,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_metadata::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionMetadata.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
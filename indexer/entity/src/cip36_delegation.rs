@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Cip36Delegation")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub registration_id: i64,
+    pub voting_key: Vec<u8>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub weight: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::cip36_registration::Entity",
+        from = "Column::RegistrationId",
+        to = "super::cip36_registration::Column::Id"
+    )]
+    Cip36Registration,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::cip36_registration::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cip36Registration.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
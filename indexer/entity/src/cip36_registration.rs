@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Cip36Registration")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub stake_credential: Vec<u8>, // same encoding as StakeCredential.credential
+    pub stake_key: Vec<u8>,
+    pub reward_address: Vec<u8>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub nonce: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub voting_purpose: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+    #[sea_orm(has_many = "super::cip36_delegation::Entity")]
+    Cip36Delegation,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,61 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Cip68Entry")]
+pub struct Model {
+    // note: the latest entry for a reference token is the one with the highest id
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub reference_asset_id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub utxo_id: i64, // output holding the reference token
+    #[sea_orm(column_type = "BigInteger")]
+    pub datum_id: i64, // inline datum containing the metadata
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::native_asset::Entity",
+        from = "Column::ReferenceAssetId",
+        to = "super::native_asset::Column::Id"
+    )]
+    NativeAsset,
+    #[sea_orm(
+        belongs_to = "super::transaction_output::Entity",
+        from = "Column::UtxoId",
+        to = "super::transaction_output::Column::Id"
+    )]
+    TransactionOutput,
+    #[sea_orm(
+        belongs_to = "super::plutus_data_hash::Entity",
+        from = "Column::DatumId",
+        to = "super::plutus_data_hash::Column::Id"
+    )]
+    PlutusDataHash,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::native_asset::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NativeAsset.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_output::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionOutput.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::plutus_data_hash::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PlutusDataHash.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,30 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Cip68Token")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub user_asset_id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub reference_asset_id: i64,
+    pub label: i32, // CIP-67 label of the user token (222, 333 or 444)
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::native_asset::Entity",
+        from = "Column::UserAssetId",
+        to = "super::native_asset::Column::Id"
+    )]
+    UserAsset,
+    #[sea_orm(
+        belongs_to = "super::native_asset::Entity",
+        from = "Column::ReferenceAssetId",
+        to = "super::native_asset::Column::Id"
+    )]
+    ReferenceAsset,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,50 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "EpochParams")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub epoch: i32,
+    pub block_id: i32, // first block of the epoch
+    // null when the parameter was never set
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_fee_a: Option<i64>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_fee_b: Option<i64>,
+    pub max_block_body_size: Option<i32>,
+    pub max_tx_size: Option<i32>,
+    pub max_block_header_size: Option<i32>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub key_deposit: Option<i64>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub pool_deposit: Option<i64>,
+    pub max_epoch: Option<i32>,
+    pub optimal_pool_count: Option<i32>,
+    pub protocol_major: Option<i32>,
+    pub protocol_minor: Option<i32>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_pool_cost: Option<i64>,
+    pub max_value_size: Option<i32>,
+    pub collateral_percentage: Option<i32>,
+    pub max_collateral_inputs: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::block::Entity",
+        from = "Column::BlockId",
+        to = "super::block::Column::Id"
+    )]
+    Block,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::block::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Block.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
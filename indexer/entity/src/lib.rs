@@ -10,8 +10,29 @@ pub mod transaction_reference_input;
 pub mod tx_credential;
 pub use sea_orm;
 pub mod asset_mint;
+pub mod asset_utxo;
 pub mod cip25_entry;
+pub mod cip25_parse_error;
+pub mod cip36_delegation;
+pub mod cip36_registration;
+pub mod cip68_entry;
+pub mod cip68_token;
+pub mod epoch_params;
+pub mod move_instantaneous_reward;
 pub mod native_asset;
+pub mod param_proposal;
 pub mod plutus_data;
 pub mod plutus_data_hash;
+pub mod pool_registration;
+pub mod pool_retirement;
+pub mod redeemer;
+pub mod script;
+pub mod stake_delegation;
+pub mod stake_pool;
 pub mod transaction_metadata;
+pub mod transaction_metadata_json;
+pub mod transaction_output_datum;
+pub mod transaction_output_script;
+pub mod unconfirmed_block;
+pub mod utxo;
+pub mod withdrawal;
@@ -0,0 +1,65 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "MoveInstantaneousReward")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub pot: i32, // pot the rewards are taken from. See MirPotValue
+    // null if the rewards are transferred to the other pot instead of a stake credential
+    #[sea_orm(column_type = "BigInteger")]
+    pub credential_id: Option<i64>,
+    // i64 according to spec. Negative = deduct from a previous MIR to the same credential
+    #[sea_orm(column_type = "BigInteger")]
+    pub amount: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+    #[sea_orm(
+        belongs_to = "super::stake_credential::Entity",
+        from = "Column::CredentialId",
+        to = "super::stake_credential::Column::Id"
+    )]
+    StakeCredential,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::stake_credential::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StakeCredential.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MirPotValue {
+    Reserves,
+    Treasury,
+}
+
+impl From<MirPotValue> for i32 {
+    fn from(item: MirPotValue) -> Self {
+        match item {
+            MirPotValue::Reserves => 0,
+            MirPotValue::Treasury => 1,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "ParamProposal")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub genesis_key: Vec<u8>, // hash of the genesis key that proposed the update
+    pub epoch: i32,           // epoch the proposal is for (applied at the start of the next one)
+    pub payload: Vec<u8>,     // full ProtocolParamUpdate (including fields not decoded below)
+    // null when the proposal doesn't change the parameter
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_fee_a: Option<i64>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_fee_b: Option<i64>,
+    pub max_block_body_size: Option<i32>,
+    pub max_tx_size: Option<i32>,
+    pub max_block_header_size: Option<i32>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub key_deposit: Option<i64>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub pool_deposit: Option<i64>,
+    pub max_epoch: Option<i32>,
+    pub optimal_pool_count: Option<i32>,
+    pub protocol_major: Option<i32>,
+    pub protocol_minor: Option<i32>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub min_pool_cost: Option<i64>,
+    pub max_value_size: Option<i32>,
+    pub collateral_percentage: Option<i32>,
+    pub max_collateral_inputs: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
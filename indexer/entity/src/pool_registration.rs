@@ -0,0 +1,59 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "PoolRegistration")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub pool_id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub vrf_keyhash: Vec<u8>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub pledge: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub cost: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub margin_numerator: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub margin_denominator: i64,
+    pub reward_address: Vec<u8>,
+    pub relays: Vec<u8>, // cbor
+    pub metadata_url: Option<String>,
+    pub metadata_hash: Option<Vec<u8>>,
+    pub payload: Vec<u8>, // cbor of the full certificate (includes the pool owners)
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::stake_pool::Entity",
+        from = "Column::PoolId",
+        to = "super::stake_pool::Column::Id"
+    )]
+    StakePool,
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::stake_pool::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StakePool.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -11,6 +11,10 @@ pub use super::asset_mint::{
     ActiveModel as AssetMintActiveModel, Column as AssetMintColumn, Entity as AssetMint,
     Model as AssetMintModel, PrimaryKey as AssetMintPrimaryKey, Relation as AssetMintRelation,
 };
+pub use super::asset_utxo::{
+    ActiveModel as AssetUtxoActiveModel, Column as AssetUtxoColumn, Entity as AssetUtxo,
+    Model as AssetUtxoModel, PrimaryKey as AssetUtxoPrimaryKey, Relation as AssetUtxoRelation,
+};
 pub use super::block::{
     ActiveModel as BlockActiveModel, Column as BlockColumn, Entity as Block, Model as BlockModel,
     PrimaryKey as BlockPrimaryKey, Relation as BlockRelation,
@@ -19,11 +23,49 @@ pub use super::cip25_entry::{
     ActiveModel as Cip25EntryActiveModel, Column as Cip25EntryColumn, Entity as Cip25Entry,
     Model as Cip25EntryModel, PrimaryKey as Cip25EntryPrimaryKey, Relation as Cip25EntryRelation,
 };
+pub use super::cip25_parse_error::{
+    ActiveModel as Cip25ParseErrorActiveModel, Column as Cip25ParseErrorColumn,
+    Entity as Cip25ParseError, Model as Cip25ParseErrorModel,
+    PrimaryKey as Cip25ParseErrorPrimaryKey, Relation as Cip25ParseErrorRelation,
+};
+pub use super::cip36_delegation::{
+    ActiveModel as Cip36DelegationActiveModel, Column as Cip36DelegationColumn,
+    Entity as Cip36Delegation, Model as Cip36DelegationModel,
+    PrimaryKey as Cip36DelegationPrimaryKey, Relation as Cip36DelegationRelation,
+};
+pub use super::cip36_registration::{
+    ActiveModel as Cip36RegistrationActiveModel, Column as Cip36RegistrationColumn,
+    Entity as Cip36Registration, Model as Cip36RegistrationModel,
+    PrimaryKey as Cip36RegistrationPrimaryKey, Relation as Cip36RegistrationRelation,
+};
+pub use super::cip68_entry::{
+    ActiveModel as Cip68EntryActiveModel, Column as Cip68EntryColumn, Entity as Cip68Entry,
+    Model as Cip68EntryModel, PrimaryKey as Cip68EntryPrimaryKey, Relation as Cip68EntryRelation,
+};
+pub use super::cip68_token::{
+    ActiveModel as Cip68TokenActiveModel, Column as Cip68TokenColumn, Entity as Cip68Token,
+    Model as Cip68TokenModel, PrimaryKey as Cip68TokenPrimaryKey, Relation as Cip68TokenRelation,
+};
+pub use super::epoch_params::{
+    ActiveModel as EpochParamsActiveModel, Column as EpochParamsColumn, Entity as EpochParams,
+    Model as EpochParamsModel, PrimaryKey as EpochParamsPrimaryKey,
+    Relation as EpochParamsRelation,
+};
+pub use super::move_instantaneous_reward::{
+    ActiveModel as MoveInstantaneousRewardActiveModel, Column as MoveInstantaneousRewardColumn,
+    Entity as MoveInstantaneousReward, Model as MoveInstantaneousRewardModel,
+    PrimaryKey as MoveInstantaneousRewardPrimaryKey, Relation as MoveInstantaneousRewardRelation,
+};
 pub use super::native_asset::{
     ActiveModel as NativeAssetActiveModel, Column as NativeAssetColumn, Entity as NativeAsset,
     Model as NativeAssetModel, PrimaryKey as NativeAssetPrimaryKey,
     Relation as NativeAssetRelation,
 };
+pub use super::param_proposal::{
+    ActiveModel as ParamProposalActiveModel, Column as ParamProposalColumn,
+    Entity as ParamProposal, Model as ParamProposalModel, PrimaryKey as ParamProposalPrimaryKey,
+    Relation as ParamProposalRelation,
+};
 pub use super::plutus_data::{
     ActiveModel as PlutusDataActiveModel, Column as PlutusDataColumn, Entity as PlutusData,
     Model as PlutusDataModel, PrimaryKey as PlutusDataPrimaryKey, Relation as PlutusDataRelation,
@@ -33,11 +75,38 @@ pub use super::plutus_data_hash::{
     Entity as PlutusDataHash, Model as PlutusDataHashModel, PrimaryKey as PlutusDataHashPrimaryKey,
     Relation as PlutusDataHashRelation,
 };
+pub use super::pool_registration::{
+    ActiveModel as PoolRegistrationActiveModel, Column as PoolRegistrationColumn,
+    Entity as PoolRegistration, Model as PoolRegistrationModel,
+    PrimaryKey as PoolRegistrationPrimaryKey, Relation as PoolRegistrationRelation,
+};
+pub use super::pool_retirement::{
+    ActiveModel as PoolRetirementActiveModel, Column as PoolRetirementColumn,
+    Entity as PoolRetirement, Model as PoolRetirementModel, PrimaryKey as PoolRetirementPrimaryKey,
+    Relation as PoolRetirementRelation,
+};
+pub use super::redeemer::{
+    ActiveModel as RedeemerActiveModel, Column as RedeemerColumn, Entity as Redeemer,
+    Model as RedeemerModel, PrimaryKey as RedeemerPrimaryKey, Relation as RedeemerRelation,
+};
+pub use super::script::{
+    ActiveModel as ScriptActiveModel, Column as ScriptColumn, Entity as Script,
+    Model as ScriptModel, PrimaryKey as ScriptPrimaryKey, Relation as ScriptRelation,
+};
 pub use super::stake_credential::{
     ActiveModel as StakeCredentialActiveModel, Column as StakeCredentialColumn,
     Entity as StakeCredential, Model as StakeCredentialModel,
     PrimaryKey as StakeCredentialPrimaryKey, Relation as StakeCredentialRelation,
 };
+pub use super::stake_delegation::{
+    ActiveModel as StakeDelegationActiveModel, Column as StakeDelegationColumn,
+    Entity as StakeDelegation, Model as StakeDelegationModel,
+    PrimaryKey as StakeDelegationPrimaryKey, Relation as StakeDelegationRelation,
+};
+pub use super::stake_pool::{
+    ActiveModel as StakePoolActiveModel, Column as StakePoolColumn, Entity as StakePool,
+    Model as StakePoolModel, PrimaryKey as StakePoolPrimaryKey, Relation as StakePoolRelation,
+};
 pub use super::transaction::{
     ActiveModel as TransactionActiveModel, Column as TransactionColumn, Entity as Transaction,
     Model as TransactionModel, PrimaryKey as TransactionPrimaryKey,
@@ -53,11 +122,26 @@ pub use super::transaction_metadata::{
     Entity as TransactionMetadata, Model as TransactionMetadataModel,
     PrimaryKey as TransactionMetadataPrimaryKey, Relation as TransactionMetadataRelation,
 };
+pub use super::transaction_metadata_json::{
+    ActiveModel as TransactionMetadataJsonActiveModel, Column as TransactionMetadataJsonColumn,
+    Entity as TransactionMetadataJson, Model as TransactionMetadataJsonModel,
+    PrimaryKey as TransactionMetadataJsonPrimaryKey, Relation as TransactionMetadataJsonRelation,
+};
 pub use super::transaction_output::{
     ActiveModel as TransactionOutputActiveModel, Column as TransactionOutputColumn,
     Entity as TransactionOutput, Model as TransactionOutputModel,
     PrimaryKey as TransactionOutputPrimaryKey, Relation as TransactionOutputRelation,
 };
+pub use super::transaction_output_datum::{
+    ActiveModel as TransactionOutputDatumActiveModel, Column as TransactionOutputDatumColumn,
+    Entity as TransactionOutputDatum, Model as TransactionOutputDatumModel,
+    PrimaryKey as TransactionOutputDatumPrimaryKey, Relation as TransactionOutputDatumRelation,
+};
+pub use super::transaction_output_script::{
+    ActiveModel as TransactionOutputScriptActiveModel, Column as TransactionOutputScriptColumn,
+    Entity as TransactionOutputScript, Model as TransactionOutputScriptModel,
+    PrimaryKey as TransactionOutputScriptPrimaryKey, Relation as TransactionOutputScriptRelation,
+};
 pub use super::transaction_reference_input::{
     ActiveModel as TransactionReferenceInputActiveModel, Column as TransactionReferenceInputColumn,
     Entity as TransactionReferenceInput, Model as TransactionReferenceInputModel,
@@ -69,3 +153,16 @@ pub use super::tx_credential::{
     Model as TxCredentialModel, PrimaryKey as TxCredentialPrimaryKey,
     Relation as TxCredentialRelation,
 };
+pub use super::unconfirmed_block::{
+    ActiveModel as UnconfirmedBlockActiveModel, Column as UnconfirmedBlockColumn,
+    Entity as UnconfirmedBlock, Model as UnconfirmedBlockModel,
+    PrimaryKey as UnconfirmedBlockPrimaryKey, Relation as UnconfirmedBlockRelation,
+};
+pub use super::utxo::{
+    ActiveModel as UtxoActiveModel, Column as UtxoColumn, Entity as Utxo, Model as UtxoModel,
+    PrimaryKey as UtxoPrimaryKey, Relation as UtxoRelation,
+};
+pub use super::withdrawal::{
+    ActiveModel as WithdrawalActiveModel, Column as WithdrawalColumn, Entity as Withdrawal,
+    Model as WithdrawalModel, PrimaryKey as WithdrawalPrimaryKey, Relation as WithdrawalRelation,
+};
@@ -0,0 +1,88 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Redeemer")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub tag: i32,   // see RedeemerTagValue
+    pub index: i32, // index of the redeemer target (in the sorted inputs for spend)
+    pub payload: Vec<u8>,
+    #[sea_orm(column_type = "BigInteger")]
+    pub ex_units_mem: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub ex_units_steps: i64,
+    // only set for spend redeemers if the datum hash is known
+    #[sea_orm(column_type = "BigInteger")]
+    pub datum_id: Option<i64>,
+    // only set for spend redeemers of valid txs (inputs aren't consumed if the tx fails)
+    #[sea_orm(column_type = "BigInteger")]
+    pub input_id: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+    #[sea_orm(
+        belongs_to = "super::plutus_data_hash::Entity",
+        from = "Column::DatumId",
+        to = "super::plutus_data_hash::Column::Id"
+    )]
+    PlutusDataHash,
+    #[sea_orm(
+        belongs_to = "super::transaction_input::Entity",
+        from = "Column::InputId",
+        to = "super::transaction_input::Column::Id"
+    )]
+    TransactionInput,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::plutus_data_hash::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PlutusDataHash.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_input::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionInput.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RedeemerTagValue {
+    Spend,
+    Mint,
+    Cert,
+    Reward,
+}
+
+impl From<RedeemerTagValue> for i32 {
+    fn from(item: RedeemerTagValue) -> Self {
+        match item {
+            RedeemerTagValue::Spend => 0,
+            RedeemerTagValue::Mint => 1,
+            RedeemerTagValue::Cert => 2,
+            RedeemerTagValue::Reward => 3,
+        }
+    }
+}
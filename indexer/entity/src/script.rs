@@ -0,0 +1,51 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "Script")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub hash: Vec<u8>,
+    pub kind: i32,        // see ScriptKindValue
+    pub payload: Vec<u8>, // cbor of the script (without the language tag)
+    #[sea_orm(column_type = "BigInteger")]
+    pub first_tx: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::FirstTx",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScriptKindValue {
+    Native,
+    PlutusV1,
+    PlutusV2,
+}
+
+impl From<ScriptKindValue> for i32 {
+    fn from(item: ScriptKindValue) -> Self {
+        match item {
+            ScriptKindValue::Native => 0,
+            ScriptKindValue::PlutusV1 => 1,
+            ScriptKindValue::PlutusV2 => 2,
+        }
+    }
+}
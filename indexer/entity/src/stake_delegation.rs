@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "StakeDelegation")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub credential_id: i64,
+    pub pool_credential: Vec<u8>, // pool keyhash
+    #[sea_orm(column_type = "BigInteger")]
+    pub tx_id: i64,
+    pub epoch: i32, // epoch of the block containing the certificate
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::stake_credential::Entity",
+        from = "Column::CredentialId",
+        to = "super::stake_credential::Column::Id"
+    )]
+    StakeCredential,
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::TxId",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::stake_credential::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StakeCredential.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
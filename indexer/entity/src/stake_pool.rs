@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "StakePool")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub operator: Vec<u8>, // pool keyhash (a.k.a. pool id)
+    #[sea_orm(column_type = "BigInteger")]
+    pub first_tx: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::pool_registration::Entity")]
+    PoolRegistration,
+    #[sea_orm(has_many = "super::pool_retirement::Entity")]
+    PoolRetirement,
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::FirstTx",
+        to = "super::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -12,6 +12,14 @@ pub struct Model {
     pub tx_index: i32, // index in block
     pub payload: Vec<u8>,
     pub is_valid: bool,
+    // note: the following are only null if the database was synced before they were introduced
+    #[sea_orm(column_type = "BigInteger")]
+    pub fee: Option<i64>, // for failed txs, the collateral that was taken instead
+    #[sea_orm(column_type = "BigInteger")]
+    pub ttl: Option<i64>, // slot after which the tx is invalid (null if unbounded)
+    #[sea_orm(column_type = "BigInteger")]
+    pub validity_start: Option<i64>, // slot before which the tx is invalid (null if unbounded)
+    pub size: Option<i32>, // size in bytes of the payload
 }
 
 #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
@@ -22,6 +22,10 @@ pub enum Relation {
     Transaction,
     #[sea_orm(has_many = "super::cip25_entry::Entity")]
     Cip25Entry,
+    #[sea_orm(has_many = "super::cip25_parse_error::Entity")]
+    Cip25ParseError,
+    #[sea_orm(has_one = "super::transaction_metadata_json::Entity")]
+    TransactionMetadataJson,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
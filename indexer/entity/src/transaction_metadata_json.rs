@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "TransactionMetadataJson")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub metadata_id: i64,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub json: Json, // "no schema" mapping of cardano-cli
+    pub message: Option<String>, // CIP-20 message (label 674 only)
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction_metadata::Entity",
+        from = "Column::MetadataId",
+        to = "super::transaction_metadata::Column::Id"
+    )]
+    TransactionMetadata,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_metadata::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionMetadata.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -12,6 +12,8 @@ pub struct Model {
     #[sea_orm(column_type = "BigInteger")]
     pub tx_id: i64,
     pub output_index: i32, // index inside transaction
+    #[sea_orm(column_type = "BigInteger")]
+    pub amount: Option<i64>, // lovelace amount (only null if synced before this was introduced)
 }
 
 #[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
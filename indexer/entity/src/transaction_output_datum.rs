@@ -0,0 +1,59 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "TransactionOutputDatum")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub utxo_id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub datum_id: i64,
+    pub kind: i32, // see OutputDatumKindValue
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction_output::Entity",
+        from = "Column::UtxoId",
+        to = "super::transaction_output::Column::Id"
+    )]
+    TransactionOutput,
+    #[sea_orm(
+        belongs_to = "super::plutus_data_hash::Entity",
+        from = "Column::DatumId",
+        to = "super::plutus_data_hash::Column::Id"
+    )]
+    PlutusDataHash,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_output::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionOutput.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::plutus_data_hash::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PlutusDataHash.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutputDatumKindValue {
+    Hash,
+    Inline,
+}
+
+impl From<OutputDatumKindValue> for i32 {
+    fn from(item: OutputDatumKindValue) -> Self {
+        match item {
+            OutputDatumKindValue::Hash => 0,
+            OutputDatumKindValue::Inline => 1,
+        }
+    }
+}
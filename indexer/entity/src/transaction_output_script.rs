@@ -0,0 +1,43 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "TransactionOutputScript")]
+pub struct Model {
+    #[sea_orm(primary_key, column_type = "BigInteger")]
+    pub utxo_id: i64,
+    #[sea_orm(column_type = "BigInteger")]
+    pub script_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::transaction_output::Entity",
+        from = "Column::UtxoId",
+        to = "super::transaction_output::Column::Id"
+    )]
+    TransactionOutput,
+    #[sea_orm(
+        belongs_to = "super::script::Entity",
+        from = "Column::ScriptId",
+        to = "super::script::Column::Id"
+    )]
+    Script,
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::transaction_output::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionOutput.def()
+    }
+}
+
+// TODO: figure out why this isn't automatically handle by the macros above
+impl Related<super::script::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Script.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,22 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// blocks received from the node that aren't deep enough to be added to the Block table yet
+// blocks are removed as soon as they are added to the Block table or rolled back,
+// so this table never contains orphaned blocks
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "UnconfirmedBlock")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32, // increasing in chain order (oldest first)
+    pub hash: Vec<u8>,
+    pub height: i32,
+    pub epoch: Option<i32>,
+    pub slot: i32,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Copy, Clone, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -15,6 +15,31 @@ mod m20220520_000010_create_cip25_entry_table;
 mod m20220528_000011_create_plutus_data_hash_table;
 mod m20220528_000012_create_plutus_data_table;
 mod m20220808_000013_create_transaction_reference_input_table;
+mod m20221025_000014_create_stake_pool_table;
+mod m20221025_000015_create_pool_registration_table;
+mod m20221025_000016_create_pool_retirement_table;
+mod m20221026_000017_create_stake_delegation_table;
+mod m20221027_000018_create_utxo_table;
+mod m20221028_000019_create_asset_utxo_table;
+mod m20221029_000020_add_value_columns;
+mod m20221030_000021_create_redeemer_table;
+mod m20221031_000022_create_script_table;
+mod m20221101_000023_create_transaction_output_datum_table;
+mod m20221102_000024_create_transaction_output_script_table;
+mod m20221103_000025_create_withdrawal_table;
+mod m20221104_000026_create_move_instantaneous_reward_table;
+mod m20221105_000027_create_cip68_entry_table;
+mod m20221105_000028_create_cip68_token_table;
+mod m20221106_000029_add_cip25_fields;
+mod m20221106_000030_create_cip25_parse_error_table;
+mod m20221107_000031_create_cip36_registration_table;
+mod m20221107_000032_create_cip36_delegation_table;
+mod m20221108_000033_create_transaction_metadata_json_table;
+mod m20221109_000034_add_block_header_columns;
+mod m20221110_000035_add_block_time_column;
+mod m20221111_000036_create_param_proposal_table;
+mod m20221111_000037_create_epoch_params_table;
+mod m20221112_000038_create_unconfirmed_block_table;
 
 pub struct Migrator;
 
@@ -37,6 +62,31 @@ impl MigratorTrait for Migrator {
             Box::new(m20220528_000011_create_plutus_data_hash_table::Migration),
             Box::new(m20220528_000012_create_plutus_data_table::Migration),
             Box::new(m20220808_000013_create_transaction_reference_input_table::Migration),
+            Box::new(m20221025_000014_create_stake_pool_table::Migration),
+            Box::new(m20221025_000015_create_pool_registration_table::Migration),
+            Box::new(m20221025_000016_create_pool_retirement_table::Migration),
+            Box::new(m20221026_000017_create_stake_delegation_table::Migration),
+            Box::new(m20221027_000018_create_utxo_table::Migration),
+            Box::new(m20221028_000019_create_asset_utxo_table::Migration),
+            Box::new(m20221029_000020_add_value_columns::Migration),
+            Box::new(m20221030_000021_create_redeemer_table::Migration),
+            Box::new(m20221031_000022_create_script_table::Migration),
+            Box::new(m20221101_000023_create_transaction_output_datum_table::Migration),
+            Box::new(m20221102_000024_create_transaction_output_script_table::Migration),
+            Box::new(m20221103_000025_create_withdrawal_table::Migration),
+            Box::new(m20221104_000026_create_move_instantaneous_reward_table::Migration),
+            Box::new(m20221105_000027_create_cip68_entry_table::Migration),
+            Box::new(m20221105_000028_create_cip68_token_table::Migration),
+            Box::new(m20221106_000029_add_cip25_fields::Migration),
+            Box::new(m20221106_000030_create_cip25_parse_error_table::Migration),
+            Box::new(m20221107_000031_create_cip36_registration_table::Migration),
+            Box::new(m20221107_000032_create_cip36_delegation_table::Migration),
+            Box::new(m20221108_000033_create_transaction_metadata_json_table::Migration),
+            Box::new(m20221109_000034_add_block_header_columns::Migration),
+            Box::new(m20221110_000035_add_block_time_column::Migration),
+            Box::new(m20221111_000036_create_param_proposal_table::Migration),
+            Box::new(m20221111_000037_create_epoch_params_table::Migration),
+            Box::new(m20221112_000038_create_unconfirmed_block_table::Migration),
         ]
     }
 }
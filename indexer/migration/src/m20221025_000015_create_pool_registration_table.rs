@@ -0,0 +1,94 @@
+use sea_schema::migration::prelude::*;
+
+use entity::pool_registration::*;
+use entity::prelude::{StakePool, StakePoolColumn, Transaction, TransactionColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221025_000015_create_pool_registration_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Column::PoolId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-pool_registration-pool_id")
+                            .from(Entity, Column::PoolId)
+                            .to(StakePool, StakePoolColumn::Id)
+                            // TODO: sea-query doesn't support RESTRICT DEFERRED
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::TxId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-pool_registration-tx_id")
+                            .from(Entity, Column::TxId)
+                            .to(Transaction, TransactionColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::VrfKeyhash).binary().not_null())
+                    .col(ColumnDef::new(Column::Pledge).big_integer().not_null())
+                    .col(ColumnDef::new(Column::Cost).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Column::MarginNumerator)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Column::MarginDenominator)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Column::RewardAddress).binary().not_null())
+                    .col(ColumnDef::new(Column::Relays).binary().not_null())
+                    .col(ColumnDef::new(Column::MetadataUrl).text())
+                    .col(ColumnDef::new(Column::MetadataHash).binary())
+                    .col(ColumnDef::new(Column::Payload).binary().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-pool_registration-pool")
+                    .col(Column::PoolId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-pool_registration-transaction")
+                    .col(Column::TxId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
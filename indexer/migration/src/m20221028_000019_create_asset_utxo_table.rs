@@ -0,0 +1,73 @@
+use sea_schema::migration::prelude::*;
+
+use entity::asset_utxo::*;
+use entity::prelude::{NativeAsset, NativeAssetColumn, TransactionOutput, TransactionOutputColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221028_000019_create_asset_utxo_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Column::UtxoId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-asset_utxo-utxo_id")
+                            .from(Entity, Column::UtxoId)
+                            .to(TransactionOutput, TransactionOutputColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::AssetId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-asset_utxo-asset_id")
+                            .from(Entity, Column::AssetId)
+                            .to(NativeAsset, NativeAssetColumn::Id)
+                            // TODO: sea-query doesn't support RESTRICT DEFERRED
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        // u64 according to spec, which doesn't fit in a BIGINT
+                        ColumnDef::new(Column::Amount).decimal_len(20, 0).not_null(),
+                    )
+                    // Note: the 2-tuple is the primary key
+                    // This creates an index on <UtxoId> and <UtxoId, AssetId> (https://stackoverflow.com/a/11352543)
+                    // so we also need to explicitly create an index on AssetId
+                    .primary_key(
+                        Index::create()
+                            .table(Entity)
+                            .name("asset_utxo-pk")
+                            .col(Column::UtxoId)
+                            .col(Column::AssetId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-asset_utxo-native_asset")
+                    .col(Column::AssetId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,92 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{Transaction, TransactionColumn, TransactionOutput, TransactionOutputColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221029_000020_add_value_columns"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // note: columns are nullable so existing databases can be migrated
+        // and then filled in with the value backfill execution plan
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransactionOutput)
+                    .add_column(ColumnDef::new(TransactionOutputColumn::Amount).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transaction)
+                    .add_column(ColumnDef::new(TransactionColumn::Fee).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transaction)
+                    .add_column(ColumnDef::new(TransactionColumn::Ttl).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transaction)
+                    .add_column(ColumnDef::new(TransactionColumn::ValidityStart).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transaction)
+                    .add_column(ColumnDef::new(TransactionColumn::Size).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransactionOutput)
+                    .drop_column(TransactionOutputColumn::Amount)
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            TransactionColumn::Fee,
+            TransactionColumn::Ttl,
+            TransactionColumn::ValidityStart,
+            TransactionColumn::Size,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Transaction)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
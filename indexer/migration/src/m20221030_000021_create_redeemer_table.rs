@@ -0,0 +1,106 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{
+    PlutusDataHash, PlutusDataHashColumn, Transaction, TransactionColumn, TransactionInput,
+    TransactionInputColumn,
+};
+use entity::redeemer::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221030_000021_create_redeemer_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Column::TxId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-redeemer-tx_id")
+                            .from(Entity, Column::TxId)
+                            .to(Transaction, TransactionColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::Tag).integer().not_null())
+                    .col(ColumnDef::new(Column::Index).integer().not_null())
+                    .col(ColumnDef::new(Column::Payload).binary().not_null())
+                    .col(ColumnDef::new(Column::ExUnitsMem).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Column::ExUnitsSteps)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Column::DatumId).big_integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-redeemer-datum_id")
+                            .from(Entity, Column::DatumId)
+                            .to(PlutusDataHash, PlutusDataHashColumn::Id)
+                            // TODO: sea-query doesn't support RESTRICT DEFERRED
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::InputId).big_integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-redeemer-input_id")
+                            .from(Entity, Column::InputId)
+                            .to(TransactionInput, TransactionInputColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-redeemer-transaction")
+                    .col(Column::TxId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-redeemer-datum")
+                    .col(Column::DatumId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-redeemer-input")
+                    .col(Column::InputId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
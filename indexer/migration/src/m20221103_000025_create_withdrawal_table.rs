@@ -0,0 +1,70 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{Address, AddressColumn, Transaction, TransactionColumn};
+use entity::withdrawal::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221103_000025_create_withdrawal_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Column::TxId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-withdrawal-tx_id")
+                            .from(Entity, Column::TxId)
+                            .to(Transaction, TransactionColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::AddressId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-withdrawal-address_id")
+                            .from(Entity, Column::AddressId)
+                            .to(Address, AddressColumn::Id)
+                            // TODO: sea-query doesn't support RESTRICT DEFERRED
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::Amount).big_integer().not_null())
+                    // Note: the 2-tuple is the primary key
+                    // This creates an index on <TxId> and <TxId, AddressId> (https://stackoverflow.com/a/11352543)
+                    // so we also need to explicitly create an index on AddressId
+                    .primary_key(
+                        Index::create()
+                            .table(Entity)
+                            .name("withdrawal-pk")
+                            .col(Column::TxId)
+                            .col(Column::AddressId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-withdrawal-address")
+                    .col(Column::AddressId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
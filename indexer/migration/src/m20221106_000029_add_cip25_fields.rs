@@ -0,0 +1,103 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{Cip25Entry, Cip25EntryColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221106_000029_add_cip25_fields"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // note: columns are nullable since every field is optional in practice
+        // and entries added before this migration only have the raw payload
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cip25Entry)
+                    .add_column(ColumnDef::new(Cip25EntryColumn::Name).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cip25Entry)
+                    .add_column(ColumnDef::new(Cip25EntryColumn::Image).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cip25Entry)
+                    .add_column(ColumnDef::new(Cip25EntryColumn::MediaType).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cip25Entry)
+                    .add_column(ColumnDef::new(Cip25EntryColumn::Description).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cip25Entry)
+                    .add_column(ColumnDef::new(Cip25EntryColumn::Files).json_binary())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Cip25Entry)
+                    .name("index-cip25_entry-name")
+                    .col(Cip25EntryColumn::Name)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .table(Cip25Entry)
+                    .name("index-cip25_entry-name")
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            Cip25EntryColumn::Files,
+            Cip25EntryColumn::Description,
+            Cip25EntryColumn::MediaType,
+            Cip25EntryColumn::Image,
+            Cip25EntryColumn::Name,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Cip25Entry)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use sea_schema::migration::prelude::*;
+
+use entity::cip25_parse_error::*;
+use entity::prelude::{TransactionMetadata, TransactionMetadataColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221106_000030_create_cip25_parse_error_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Id)
+                            .big_integer()
+                            .primary_key()
+                            .auto_increment()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Column::MetadataId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-cip25_parse_error-metadata")
+                            .from(Entity, Column::MetadataId)
+                            .to(TransactionMetadata, TransactionMetadataColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::PolicyId).binary())
+                    .col(ColumnDef::new(Column::AssetName).binary())
+                    .col(ColumnDef::new(Column::Reason).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-cip25_parse_error-metadata")
+                    .col(Column::MetadataId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-cip25_parse_error-policy_id")
+                    .col(Column::PolicyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,94 @@
+use sea_schema::migration::prelude::*;
+
+use entity::cip36_registration::*;
+use entity::prelude::{Transaction, TransactionColumn};
+use sea_orm::{ConnectionTrait, Statement};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221107_000031_create_cip36_registration_table"
+    }
+}
+
+// registrations are never deleted so rollbacks can restore the previous one
+// instead, this view only keeps the one with the highest nonce for each stake credential
+const LATEST_REGISTRATION_VIEW: &str = r#"
+CREATE VIEW "Cip36LatestRegistration" AS
+SELECT DISTINCT ON ("stake_credential") *
+FROM "Cip36Registration"
+ORDER BY "stake_credential", "nonce" DESC, "id" DESC
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Column::TxId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-cip36_registration-tx_id")
+                            .from(Entity, Column::TxId)
+                            .to(Transaction, TransactionColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::StakeCredential).binary().not_null())
+                    .col(ColumnDef::new(Column::StakeKey).binary().not_null())
+                    .col(ColumnDef::new(Column::RewardAddress).binary().not_null())
+                    .col(ColumnDef::new(Column::Nonce).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Column::VotingPurpose)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-cip36_registration-stake_credential")
+                    .col(Column::StakeCredential)
+                    .col(Column::Nonce)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                LATEST_REGISTRATION_VIEW.to_owned(),
+            ))
+            .await
+            .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                r#"DROP VIEW IF EXISTS "Cip36LatestRegistration""#.to_owned(),
+            ))
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
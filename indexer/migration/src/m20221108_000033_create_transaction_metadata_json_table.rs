@@ -0,0 +1,70 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{TransactionMetadata, TransactionMetadataColumn};
+use entity::transaction_metadata_json::*;
+use sea_orm::{ConnectionTrait, Statement};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221108_000033_create_transaction_metadata_json_table"
+    }
+}
+
+// sea-query can't create GIN indexes (or indexes on an expression) for Postgres
+const JSON_INDEX: &str = r#"
+CREATE INDEX "index-transaction_metadata_json-json"
+ON "TransactionMetadataJson" USING GIN ("json")
+"#;
+const MESSAGE_INDEX: &str = r#"
+CREATE INDEX "index-transaction_metadata_json-message"
+ON "TransactionMetadataJson" USING GIN (to_tsvector('simple', "message"))
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::MetadataId)
+                            .big_integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-transaction_metadata_json-metadata_id")
+                            .from(Entity, Column::MetadataId)
+                            .to(TransactionMetadata, TransactionMetadataColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::Json).json_binary().not_null())
+                    .col(ColumnDef::new(Column::Message).text())
+                    .to_owned(),
+            )
+            .await?;
+
+        for index in [JSON_INDEX, MESSAGE_INDEX] {
+            manager
+                .get_connection()
+                .execute(Statement::from_string(
+                    manager.get_database_backend(),
+                    index.to_owned(),
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
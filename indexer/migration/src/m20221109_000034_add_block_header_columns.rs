@@ -0,0 +1,108 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{Block, BlockColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221109_000034_add_block_header_columns"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // note: columns are nullable so existing databases can be migrated
+        // and then filled in with the block backfill execution plan
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::PoolId).binary())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::VrfOutput).binary())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::ProtocolMajor).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::ProtocolMinor).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::BodySize).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::TxCount).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Block)
+                    .name("index-block-pool_id")
+                    .col(BlockColumn::PoolId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .table(Block)
+                    .name("index-block-pool_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            BlockColumn::PoolId,
+            BlockColumn::VrfOutput,
+            BlockColumn::ProtocolMajor,
+            BlockColumn::ProtocolMinor,
+            BlockColumn::BodySize,
+            BlockColumn::TxCount,
+        ] {
+            manager
+                .alter_table(Table::alter().table(Block).drop_column(column).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use sea_schema::migration::prelude::*;
+
+use entity::prelude::{Block, BlockColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221110_000035_add_block_time_column"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // note: column is nullable so existing databases can be migrated
+        // and then filled in with the block backfill execution plan
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .add_column(ColumnDef::new(BlockColumn::Time).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Block)
+                    .name("index-block-time")
+                    .col(BlockColumn::Time)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .table(Block)
+                    .name("index-block-time")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Block)
+                    .drop_column(BlockColumn::Time)
+                    .to_owned(),
+            )
+            .await
+    }
+}
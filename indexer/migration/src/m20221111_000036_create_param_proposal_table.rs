@@ -0,0 +1,75 @@
+use sea_schema::migration::prelude::*;
+
+use entity::param_proposal::*;
+use entity::prelude::{Transaction, TransactionColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221111_000036_create_param_proposal_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Column::TxId).big_integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-param_proposal-tx_id")
+                            .from(Entity, Column::TxId)
+                            .to(Transaction, TransactionColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::GenesisKey).binary().not_null())
+                    .col(ColumnDef::new(Column::Epoch).integer().not_null())
+                    .col(ColumnDef::new(Column::Payload).binary().not_null())
+                    .col(ColumnDef::new(Column::MinFeeA).big_integer())
+                    .col(ColumnDef::new(Column::MinFeeB).big_integer())
+                    .col(ColumnDef::new(Column::MaxBlockBodySize).integer())
+                    .col(ColumnDef::new(Column::MaxTxSize).integer())
+                    .col(ColumnDef::new(Column::MaxBlockHeaderSize).integer())
+                    .col(ColumnDef::new(Column::KeyDeposit).big_integer())
+                    .col(ColumnDef::new(Column::PoolDeposit).big_integer())
+                    .col(ColumnDef::new(Column::MaxEpoch).integer())
+                    .col(ColumnDef::new(Column::OptimalPoolCount).integer())
+                    .col(ColumnDef::new(Column::ProtocolMajor).integer())
+                    .col(ColumnDef::new(Column::ProtocolMinor).integer())
+                    .col(ColumnDef::new(Column::MinPoolCost).big_integer())
+                    .col(ColumnDef::new(Column::MaxValueSize).integer())
+                    .col(ColumnDef::new(Column::CollateralPercentage).integer())
+                    .col(ColumnDef::new(Column::MaxCollateralInputs).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Entity)
+                    .name("index-param_proposal-epoch")
+                    .col(Column::Epoch)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
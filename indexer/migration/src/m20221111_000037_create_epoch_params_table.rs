@@ -0,0 +1,61 @@
+use sea_schema::migration::prelude::*;
+
+use entity::epoch_params::*;
+use entity::prelude::{Block, BlockColumn};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221111_000037_create_epoch_params_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Column::Epoch)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Column::BlockId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-epoch_params-block_id")
+                            .from(Entity, Column::BlockId)
+                            .to(Block, BlockColumn::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Column::MinFeeA).big_integer())
+                    .col(ColumnDef::new(Column::MinFeeB).big_integer())
+                    .col(ColumnDef::new(Column::MaxBlockBodySize).integer())
+                    .col(ColumnDef::new(Column::MaxTxSize).integer())
+                    .col(ColumnDef::new(Column::MaxBlockHeaderSize).integer())
+                    .col(ColumnDef::new(Column::KeyDeposit).big_integer())
+                    .col(ColumnDef::new(Column::PoolDeposit).big_integer())
+                    .col(ColumnDef::new(Column::MaxEpoch).integer())
+                    .col(ColumnDef::new(Column::OptimalPoolCount).integer())
+                    .col(ColumnDef::new(Column::ProtocolMajor).integer())
+                    .col(ColumnDef::new(Column::ProtocolMinor).integer())
+                    .col(ColumnDef::new(Column::MinPoolCost).big_integer())
+                    .col(ColumnDef::new(Column::MaxValueSize).integer())
+                    .col(ColumnDef::new(Column::CollateralPercentage).integer())
+                    .col(ColumnDef::new(Column::MaxCollateralInputs).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
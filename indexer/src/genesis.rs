@@ -3,44 +3,145 @@ use entity::block::EraValue;
 use std::fs;
 use std::sync::{Arc, Mutex};
 use tasks::dsl::database_task::BlockGlobalInfo;
+use tasks::genesis::genesis_files::{AlonzoGenesisData, GenesisFiles, ShelleyGenesisData};
+use tasks::time::TimeParams;
 
-use cardano_multiplatform_lib::genesis::byron::{config::GenesisData, parse::parse};
+use crate::network::NetworkConfig;
+
+use cardano_multiplatform_lib::genesis::byron::parse::parse;
 use entity::sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 use migration::DbErr;
-use tasks::utils::TaskPerfAggregator;
+use tasks::utils::{blake2b256, TaskPerfAggregator};
 use tasks::{execution_plan::ExecutionPlan, genesis::genesis_executor::process_genesis_block};
 
-const GENESIS_MAINNET: &str = "./genesis/mainnet-byron-genesis.json";
-const GENESIS_PREVIEW: &str = "./genesis/preview-byron-genesis.json";
-const GENESIS_PREPROD: &str = "./genesis/preprod-byron-genesis.json";
-const GENESIS_TESTNET: &str = "./genesis/testnet-byron-genesis.json";
+fn parse_shelley_genesis(path: &Option<String>) -> anyhow::Result<Option<ShelleyGenesisData>> {
+    let path = match path {
+        None => return Ok(None),
+        Some(path) => path,
+    };
+    let bytes = fs::read(path).map_err(|err| anyhow!("Failed to read {}: {}", path, err))?;
+    let mut genesis: ShelleyGenesisData = serde_json::from_slice(&bytes)
+        .map_err(|err| anyhow!("Failed to parse {}: {}", path, err))?;
+    genesis.hash = blake2b256(&bytes);
+    Ok(Some(genesis))
+}
+
+fn parse_alonzo_genesis(path: &Option<String>) -> anyhow::Result<Option<AlonzoGenesisData>> {
+    let path = match path {
+        None => return Ok(None),
+        Some(path) => path,
+    };
+    let file = fs::File::open(path).map_err(|err| anyhow!("Failed to read {}: {}", path, err))?;
+    let genesis = serde_json::from_reader(file)
+        .map_err(|err| anyhow!("Failed to parse {}: {}", path, err))?;
+    Ok(Some(genesis))
+}
+
+/// Builds the parameters to convert slots to time & epochs from the network config & genesis files
+/// This is the only source of era boundaries: oura's well known info is derived from it
+pub fn get_time_params(network: &NetworkConfig) -> anyhow::Result<TimeParams> {
+    // note: we only need a few fields so we avoid parsing the whole file as GenesisData
+    let file = fs::File::open(&network.byron_genesis)?;
+    let genesis: serde_json::Value = serde_json::from_reader(file)?;
+    let system_start = genesis["startTime"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("startTime missing from the Byron genesis file"))?;
+    let byron_slot_length = match network.byron_slot_length {
+        Some(slot_length) => slot_length,
+        None => genesis["blockVersionData"]["slotDuration"]
+            .as_str()
+            .and_then(|duration| duration.parse::<u64>().ok())
+            .ok_or_else(|| anyhow!("slotDuration missing from the Byron genesis file"))?,
+    };
+    let byron_epoch_length = match network.byron_epoch_length {
+        Some(epoch_length) => epoch_length,
+        // a Byron epoch always lasts 10k slots
+        None => {
+            10 * genesis["protocolConsts"]["k"]
+                .as_u64()
+                .ok_or_else(|| anyhow!("protocolConsts.k missing from the Byron genesis file"))?
+        }
+    };
+
+    let (shelley_slot_length, shelley_epoch_length) =
+        match (network.shelley_slot_length, network.shelley_epoch_length) {
+            (Some(slot_length), Some(epoch_length)) => (slot_length, epoch_length),
+            (slot_length, epoch_length) => {
+                let (genesis_slot_length, genesis_epoch_length) = get_shelley_lengths(network)?;
+                (
+                    slot_length.unwrap_or(genesis_slot_length),
+                    epoch_length.unwrap_or(genesis_epoch_length),
+                )
+            }
+        };
+
+    Ok(TimeParams {
+        system_start: system_start * 1000,
+        byron_slot_length,
+        byron_epoch_length,
+        shelley_start_slot: network.shelley_start_slot,
+        shelley_slot_length,
+        shelley_epoch_length,
+    })
+}
+
+/// Shelley slot length (in milliseconds) & epoch length from the genesis file or the presets
+fn get_shelley_lengths(network: &NetworkConfig) -> anyhow::Result<(u64, u64)> {
+    match parse_shelley_genesis(&network.shelley_genesis)? {
+        Some(shelley) => Ok((
+            (shelley.slot_length * 1000.0).round() as u64,
+            shelley.epoch_length,
+        )),
+        None => network.known_shelley_lengths().ok_or_else(|| {
+            anyhow!(
+                "the Shelley genesis file of {} is required to know its slot & epoch lengths",
+                network.name
+            )
+        }),
+    }
+}
+
+/// Number of genesis delegates that have to propose the same parameter update for it to be applied
+pub fn get_update_quorum(network: &NetworkConfig) -> anyhow::Result<u64> {
+    if let Some(update_quorum) = network.update_quorum {
+        return Ok(update_quorum);
+    }
+    match parse_shelley_genesis(&network.shelley_genesis)? {
+        Some(shelley) => Ok(shelley.update_quorum),
+        None => network.known_update_quorum().ok_or_else(|| {
+            anyhow!(
+                "the Shelley genesis file of {} is required to know its update quorum",
+                network.name
+            )
+        }),
+    }
+}
 
 pub async fn process_genesis(
     conn: &DatabaseConnection,
-    network: &str,
+    network: &NetworkConfig,
+    time_params: &TimeParams,
     exec_plan: Arc<ExecutionPlan>,
 ) -> anyhow::Result<()> {
-    // https://github.com/txpipe/oura/blob/67b01e8739ed2927ced270e08daea74b03bcc7f7/src/sources/common.rs#L91
-    let genesis_path = match network {
-        "mainnet" => GENESIS_MAINNET,
-        "testnet" => GENESIS_TESTNET,
-        "preview" => GENESIS_PREVIEW,
-        "preprod" => GENESIS_PREPROD,
-        rest => {
-            return Err(anyhow!(
-                "{} is invalid. NETWORK must be either mainnet/preview/preprod/testnet",
-                rest
-            ))
-        }
-    };
-
     let task_perf_aggregator = Arc::new(Mutex::new(TaskPerfAggregator::default()));
 
     tracing::info!("Parsing genesis file...");
     let mut time_counter = std::time::Instant::now();
 
-    let file = fs::File::open(genesis_path).expect("Failed to open genesis file");
-    let genesis_file: Box<GenesisData> = Box::new(parse(file));
+    if network.shelley_genesis.is_none() {
+        // note: the initialFunds & staking of the Shelley genesis never appear in a transaction
+        tracing::warn!(
+            "No Shelley genesis for {}: its initial funds, staking and parameters won't be indexed. Add ./genesis/{}-shelley-genesis.json or use --network-config",
+            network.name,
+            network.name
+        );
+    }
+    let file = fs::File::open(&network.byron_genesis).expect("Failed to open genesis file");
+    let genesis_file = Box::new(GenesisFiles {
+        byron: parse(file),
+        shelley: parse_shelley_genesis(&network.shelley_genesis)?,
+        alonzo: parse_alonzo_genesis(&network.alonzo_genesis)?,
+    });
 
     tracing::info!(
         "Finished parsing genesis file after {:?}",
@@ -53,6 +154,7 @@ pub async fn process_genesis(
         Box::pin(insert_genesis(
             txn,
             genesis_file,
+            *time_params,
             exec_plan.clone(),
             task_perf_aggregator.clone(),
         ))
@@ -73,11 +175,12 @@ pub async fn process_genesis(
 
 pub async fn insert_genesis(
     txn: &DatabaseTransaction,
-    genesis_file: Box<GenesisData>,
+    genesis_file: Box<GenesisFiles>,
+    time_params: TimeParams,
     exec_plan: Arc<ExecutionPlan>,
     task_perf_aggregator: Arc<Mutex<TaskPerfAggregator>>,
 ) -> Result<(), DbErr> {
-    let genesis_hash = genesis_file.genesis_prev.to_bytes();
+    let genesis_hash = genesis_file.byron.genesis_prev.to_bytes();
     tracing::info!(
         "Starting sync based on genesis hash {}",
         hex::encode(genesis_hash.clone())
@@ -87,6 +190,7 @@ pub async fn insert_genesis(
         era: EraValue::Byron,
         epoch: None,
         epoch_slot: None,
+        time: time_params.slot_to_time(0),
     };
 
     process_genesis_block(
@@ -3,14 +3,21 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use dotenv::dotenv;
 
+use entity::block::EraValue;
 use entity::sea_orm::Database;
+use network::NetworkConfig;
 use oura::sources::IntersectArg;
+use postgres_sink::{StopPoint, SyncEnd};
+use setup::Source;
 use tasks::execution_plan::ExecutionPlan;
 use tracing_subscriber::prelude::*;
 
 mod genesis;
+mod network;
+mod offline;
 mod perf_aggregator;
 mod postgres_sink;
+mod recorder;
 mod setup;
 mod types;
 use clap::Parser;
@@ -26,6 +33,66 @@ struct Args {
     /// This is instead meant to make it easier to write database migrations
     #[clap(short, long)]
     start_block: Option<String>,
+
+    /// Path of a TOML file defining a custom network (ex: a private devnet)
+    /// If not provided, the NETWORK env variable is used to pick one of the public networks
+    #[clap(long)]
+    network_config: Option<String>,
+
+    /// Where to get blocks from. The SOCKET env variable is the path of the node socket for n2c
+    /// and the host:port of the relay for n2n
+    #[clap(long, value_enum, default_value = "n2c")]
+    source: setup::Source,
+
+    /// Directory containing the blocks for the cbor-files and immutable-db sources
+    #[clap(long)]
+    blocks_dir: Option<String>,
+
+    /// Recording to read events from for the replay source
+    #[clap(long)]
+    replay_file: Option<String>,
+
+    /// Write every event received from the source (including rollbacks) to this file
+    /// so that the sync can be reproduced later with the replay source
+    #[clap(long)]
+    record: Option<String>,
+
+    /// Exit after the last block at or before this slot
+    #[clap(long, group = "stop")]
+    stop_at_slot: Option<u64>,
+
+    /// Exit after the block with this hash
+    #[clap(long, group = "stop")]
+    stop_at_block: Option<String>,
+
+    /// Exit after the last block of this epoch
+    #[clap(long, group = "stop")]
+    stop_at_epoch: Option<u64>,
+
+    /// Exit before the first block of this era (ex: babbage)
+    #[clap(long, group = "stop", value_parser = parse_era)]
+    stop_at_era: Option<EraValue>,
+
+    /// Only add blocks to the database once they are this many blocks deep
+    /// Rollbacks of blocks that aren't deep enough yet are handled in memory without touching the database
+    #[clap(long, default_value = "0")]
+    confirmation_depth: usize,
+
+    /// Keep the blocks that aren't confirmation-depth deep yet in the UnconfirmedBlock table
+    #[clap(long)]
+    expose_unconfirmed: bool,
+}
+
+fn parse_era(era: &str) -> Result<EraValue, String> {
+    match era.to_lowercase().as_str() {
+        "byron" => Ok(EraValue::Byron),
+        "shelley" => Ok(EraValue::Shelley),
+        "allegra" => Ok(EraValue::Allegra),
+        "mary" => Ok(EraValue::Mary),
+        "alonzo" => Ok(EraValue::Alonzo),
+        "babbage" => Ok(EraValue::Babbage),
+        rest => Err(format!("{} is not a known era", rest)),
+    }
 }
 
 #[tokio::main]
@@ -51,11 +118,19 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
     let args = Args::parse();
 
-    let network = std::env::var("NETWORK").expect("env NETWORK not found");
-    let socket = std::env::var("SOCKET").expect("env SOCKET not found");
+    let network = match &args.network_config {
+        Some(path) => NetworkConfig::load_from_file(path)?,
+        None => {
+            NetworkConfig::from_preset(&std::env::var("NETWORK").expect("env NETWORK not found"))?
+        }
+    };
+    tracing::info!("Network {} (magic {})", network.name, network.magic);
 
     let postgres_url = std::env::var("DATABASE_URL").expect("env DATABASE_URL not found");
 
+    let time_params = genesis::get_time_params(&network)?;
+    let update_quorum = genesis::get_update_quorum(&network)?;
+
     tracing::info!("Execution plan {}", args.plan);
     let exec_plan = Arc::new(ExecutionPlan::load_from_file(&args.plan));
 
@@ -72,7 +147,7 @@ async fn main() -> anyhow::Result<()> {
     let intersect = match points {
         points if points.is_empty() => {
             // insert genesis then fetch points again
-            genesis::process_genesis(&conn, &network, exec_plan.clone()).await?;
+            genesis::process_genesis(&conn, &network, &time_params, exec_plan.clone()).await?;
             // we need a special intersection type when bootstrapping from genesis
             IntersectArg::Origin
         }
@@ -92,15 +167,62 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
-    let (handles, input) = setup::oura_bootstrap(intersect, &network, socket)?;
+    let (mut handles, mut input) = match args.source {
+        Source::N2c | Source::N2n => {
+            let socket = std::env::var("SOCKET").expect("env SOCKET not found");
+            setup::oura_bootstrap(intersect, &network, time_params, args.source, socket)?
+        }
+        Source::CborFiles | Source::ImmutableDb => {
+            let blocks_dir = args
+                .blocks_dir
+                .as_ref()
+                .ok_or_else(|| anyhow!("--blocks-dir is required for {:?}", args.source))?;
+            offline::offline_bootstrap(intersect, time_params, args.source, blocks_dir)?
+        }
+        Source::Replay => {
+            let replay_file = args
+                .replay_file
+                .as_ref()
+                .ok_or_else(|| anyhow!("--replay-file is required for {:?}", args.source))?;
+            recorder::replay_bootstrap(intersect, replay_file)?
+        }
+    };
+    if let Some(path) = &args.record {
+        let (record_handle, recorded_input) = recorder::record(input, path)?;
+        handles.push(record_handle);
+        input = recorded_input;
+    }
 
-    let sink_setup = postgres_sink::Config { conn: &conn };
+    // note: clap makes sure at most one of these is set
+    let stop_at = args
+        .stop_at_slot
+        .map(StopPoint::Slot)
+        .or_else(|| args.stop_at_block.clone().map(StopPoint::BlockHash))
+        .or_else(|| args.stop_at_epoch.map(StopPoint::Epoch))
+        .or_else(|| args.stop_at_era.map(StopPoint::Era));
+
+    let sink_setup = postgres_sink::Config {
+        conn: &conn,
+        time_params,
+        update_quorum: update_quorum as usize,
+        stop_at,
+        confirmation_depth: args.confirmation_depth,
+        expose_unconfirmed: args.expose_unconfirmed,
+        finite_source: !matches!(args.source, Source::N2c | Source::N2n),
+    };
 
     let initial_point = args.start_block.as_ref().map(|_| points.first().unwrap());
-    sink_setup.start(input, exec_plan, initial_point).await?;
-
-    for handle in handles {
-        handle.join().map_err(|_| anyhow!(""))?;
+    match sink_setup.start(input, exec_plan, initial_point).await? {
+        // note: the source may still be waiting on the node so we don't wait for it
+        SyncEnd::StopPointReached => tracing::info!("{}", "Reached the stop point"),
+        SyncEnd::SourceEnded => {
+            for handle in handles {
+                // note: the error itself was already logged by the panicking thread
+                handle
+                    .join()
+                    .map_err(|_| anyhow!("The source stopped because of an error"))?;
+            }
+        }
     }
 
     Ok(())
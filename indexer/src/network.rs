@@ -0,0 +1,162 @@
+use std::path::Path;
+
+use anyhow::anyhow;
+use oura::utils::ChainWellKnownInfo;
+use serde::Deserialize;
+use tasks::time::TimeParams;
+
+// first slot of the Shelley era for each network
+// the hard fork isn't part of the genesis files so this has to be hardcoded
+const SHELLEY_START_SLOT_MAINNET: u64 = 4492800; // epoch 208
+const SHELLEY_START_SLOT_PREVIEW: u64 = 0; // preview never had Byron blocks
+const SHELLEY_START_SLOT_PREPROD: u64 = 86400; // epoch 4
+const SHELLEY_START_SLOT_TESTNET: u64 = 1598400; // epoch 74
+
+const MAGIC_MAINNET: u64 = 764824073;
+const MAGIC_PREVIEW: u64 = 2;
+const MAGIC_PREPROD: u64 = 1;
+const MAGIC_TESTNET: u64 = 1097911063;
+
+// slotLength (in milliseconds) & epochLength of the Shelley genesis files of the public networks
+const SHELLEY_SLOT_LENGTH_PUBLIC: u64 = 1000;
+const SHELLEY_EPOCH_LENGTH_MAINNET: u64 = 432000;
+const SHELLEY_EPOCH_LENGTH_PREVIEW: u64 = 86400;
+const SHELLEY_EPOCH_LENGTH_PREPROD: u64 = 432000;
+const SHELLEY_EPOCH_LENGTH_TESTNET: u64 = 432000;
+// updateQuorum of the Shelley genesis files of the public networks
+const UPDATE_QUORUM_PUBLIC: u64 = 5;
+
+/// Everything Carp needs to know about the network it indexes
+#[derive(Debug, Clone, Deserialize)]
+pub struct NetworkConfig {
+    pub name: String,
+    pub magic: u64,
+    pub byron_genesis: String,
+    // the Shelley & Alonzo genesis files are optional
+    // networks that start in Byron (ex: mainnet) have nothing in them that isn't also on-chain
+    pub shelley_genesis: Option<String>,
+    pub alonzo_genesis: Option<String>,
+    pub shelley_start_slot: u64,
+    // overrides of the values read from the genesis files (slot lengths are in milliseconds)
+    pub byron_slot_length: Option<u64>,
+    pub byron_epoch_length: Option<u64>,
+    pub shelley_slot_length: Option<u64>,
+    pub shelley_epoch_length: Option<u64>,
+    pub update_quorum: Option<u64>,
+}
+
+impl NetworkConfig {
+    /// Config for one of the public networks, using the genesis files of the genesis folder
+    pub fn from_preset(network: &str) -> anyhow::Result<Self> {
+        // https://github.com/txpipe/oura/blob/67b01e8739ed2927ced270e08daea74b03bcc7f7/src/sources/common.rs#L91
+        let (magic, shelley_start_slot) = match network {
+            "mainnet" => (MAGIC_MAINNET, SHELLEY_START_SLOT_MAINNET),
+            "testnet" => (MAGIC_TESTNET, SHELLEY_START_SLOT_TESTNET),
+            "preview" => (MAGIC_PREVIEW, SHELLEY_START_SLOT_PREVIEW),
+            "preprod" => (MAGIC_PREPROD, SHELLEY_START_SLOT_PREPROD),
+            rest => {
+                return Err(anyhow!(
+                    "{} is invalid. NETWORK must be either mainnet/preview/preprod/testnet",
+                    rest
+                ))
+            }
+        };
+        let existing_path = |era: &str| {
+            let path = format!("./genesis/{}-{}-genesis.json", network, era);
+            Path::new(&path).exists().then_some(path)
+        };
+
+        Ok(NetworkConfig {
+            name: network.to_string(),
+            magic,
+            byron_genesis: format!("./genesis/{}-byron-genesis.json", network),
+            shelley_genesis: existing_path("shelley"),
+            alonzo_genesis: existing_path("alonzo"),
+            shelley_start_slot,
+            byron_slot_length: None,
+            byron_epoch_length: None,
+            shelley_slot_length: None,
+            shelley_epoch_length: None,
+            update_quorum: None,
+        })
+    }
+
+    /// Config for a custom network (ex: a private devnet) defined in a TOML file
+    pub fn load_from_file(path: &str) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read network config {}: {}", path, err))?;
+        toml::from_str(&content)
+            .map_err(|err| anyhow!("Failed to parse network config {}: {}", path, err))
+    }
+
+    /// Shelley slot length (in milliseconds) & epoch length of the public networks
+    /// Used when their Shelley genesis file isn't in the genesis folder
+    pub fn known_shelley_lengths(&self) -> Option<(u64, u64)> {
+        let epoch_length = match self.magic {
+            MAGIC_MAINNET => SHELLEY_EPOCH_LENGTH_MAINNET,
+            MAGIC_PREVIEW => SHELLEY_EPOCH_LENGTH_PREVIEW,
+            MAGIC_PREPROD => SHELLEY_EPOCH_LENGTH_PREPROD,
+            MAGIC_TESTNET => SHELLEY_EPOCH_LENGTH_TESTNET,
+            _ => return None,
+        };
+        Some((SHELLEY_SLOT_LENGTH_PUBLIC, epoch_length))
+    }
+
+    /// Number of genesis delegates required to update the protocol parameters of the public networks
+    /// Used when their Shelley genesis file isn't in the genesis folder
+    pub fn known_update_quorum(&self) -> Option<u64> {
+        match self.magic {
+            MAGIC_MAINNET | MAGIC_PREVIEW | MAGIC_PREPROD | MAGIC_TESTNET => {
+                Some(UPDATE_QUORUM_PUBLIC)
+            }
+            _ => None,
+        }
+    }
+
+    /// Era boundaries in the format oura expects, derived from the time params of the network
+    /// note: oura only supports slot lengths in whole seconds, so other slot lengths are expressed
+    /// in slots instead. Only the number of slots per epoch matters since Carp doesn't use the
+    /// epochs & timestamps computed by oura
+    pub fn well_known_info(&self, time_params: &TimeParams) -> ChainWellKnownInfo {
+        let as_oura_lengths = |slot_length: u64, epoch_length: u64| match slot_length % 1000 {
+            0 => (slot_length / 1000, epoch_length * slot_length / 1000),
+            _ => (1, epoch_length),
+        };
+        let (byron_slot_length, byron_epoch_length) = as_oura_lengths(
+            time_params.byron_slot_length,
+            time_params.byron_epoch_length,
+        );
+        let (shelley_slot_length, shelley_epoch_length) = as_oura_lengths(
+            time_params.shelley_slot_length,
+            time_params.shelley_epoch_length,
+        );
+
+        // hashes & address info are only known for the networks oura has hardcoded
+        let known = ChainWellKnownInfo::try_from_magic(self.magic).ok();
+        ChainWellKnownInfo {
+            byron_epoch_length: byron_epoch_length as u32,
+            byron_slot_length: byron_slot_length as u32,
+            byron_known_slot: 0,
+            byron_known_hash: known
+                .as_ref()
+                .map(|known| known.byron_known_hash.clone())
+                .unwrap_or_default(),
+            byron_known_time: time_params.slot_to_time(0) / 1000,
+            shelley_epoch_length: shelley_epoch_length as u32,
+            shelley_slot_length: shelley_slot_length as u32,
+            shelley_known_slot: time_params.shelley_start_slot,
+            shelley_known_hash: known
+                .as_ref()
+                .map(|known| known.shelley_known_hash.clone())
+                .unwrap_or_default(),
+            shelley_known_time: time_params.slot_to_time(time_params.shelley_start_slot) / 1000,
+            address_hrp: match self.magic {
+                MAGIC_MAINNET => "addr".to_string(),
+                _ => "addr_test".to_string(),
+            },
+            adahandle_policy: known
+                .map(|known| known.adahandle_policy)
+                .unwrap_or_default(),
+        }
+    }
+}
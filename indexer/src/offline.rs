@@ -0,0 +1,263 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{sync_channel, Receiver},
+    thread::JoinHandle,
+};
+
+use anyhow::anyhow;
+use oura::sources::{IntersectArg, PointArg};
+use pallas::codec::minicbor;
+use pallas::ledger::traverse::MultiEraBlock;
+use tasks::time::TimeParams;
+
+use crate::setup::Source;
+use crate::types::{BlockEvent, CarpEvent};
+
+// how many blocks can be read ahead of the sink
+const READ_AHEAD: usize = 1000;
+// number of slots in a Byron epoch, as used by pallas to compute absolute slots
+const BYRON_EPOCH_LENGTH: u64 = 21600;
+
+/// Reads blocks from files instead of a node
+/// Blocks are expected in the same format cardano-node stores & sends them (era tag + block)
+pub fn offline_bootstrap(
+    intersect: IntersectArg,
+    time_params: TimeParams,
+    source: Source,
+    blocks_dir: &str,
+) -> anyhow::Result<(Vec<JoinHandle<()>>, Receiver<CarpEvent>)> {
+    let files = match source {
+        // ImmutableDB also contains index files (.primary & .secondary) we don't need
+        Source::ImmutableDb => list_files(blocks_dir, |path| {
+            path.extension().map_or(false, |ext| ext == "chunk")
+        })?,
+        Source::CborFiles => list_files(blocks_dir, |_| true)?,
+        Source::N2c | Source::N2n | Source::Replay => {
+            return Err(anyhow!("{:?} doesn't read blocks from files", source))
+        }
+    };
+    tracing::info!(
+        "Reading blocks from {} files in {}",
+        files.len(),
+        blocks_dir
+    );
+
+    // blocks up to the intersection are already in the database
+    let start = match intersect {
+        IntersectArg::Fallbacks(points) => points.first().cloned(),
+        IntersectArg::Point(point) => Some(point),
+        _ => None,
+    };
+
+    let (event_tx, event_rx) = sync_channel(READ_AHEAD);
+    let handle = std::thread::spawn(move || {
+        let mut skipping = start.is_some();
+        for file in files {
+            let content = read_file(&file)
+                .unwrap_or_else(|e| panic!("Failed to read {}: {}", file.display(), e));
+            for cbor in split_blocks(&content)
+                .unwrap_or_else(|e| panic!("Failed to split {}: {}", file.display(), e))
+            {
+                // only the header is read for the blocks before the intersection
+                if let Some(PointArg(start_slot, _)) = start.as_ref().filter(|_| skipping) {
+                    let slot = peek_slot(cbor).unwrap_or_else(|e| {
+                        panic!("Failed to read a block header in {}: {}", file.display(), e)
+                    });
+                    if slot < *start_slot {
+                        continue;
+                    }
+                }
+
+                let block = MultiEraBlock::decode(cbor).unwrap_or_else(|e| {
+                    panic!("Failed to decode block in {}: {}", file.display(), e)
+                });
+                let hash = hex::encode(block.hash());
+                if let Some(PointArg(start_slot, start_hash)) = start.as_ref().filter(|_| skipping)
+                {
+                    // note: a Byron epoch boundary block has the same slot as the block after it
+                    if block.slot() > *start_slot {
+                        panic!(
+                            "Intersection point {} at slot {} not found in the block files",
+                            start_hash, start_slot
+                        );
+                    }
+                    skipping = hash != *start_hash;
+                    continue;
+                }
+
+                let (epoch, epoch_slot) = time_params.slot_to_epoch(block.slot());
+                let event = CarpEvent::Block(BlockEvent {
+                    cbor_hex: hex::encode(cbor),
+                    hash,
+                    number: block.number(),
+                    slot: block.slot(),
+                    epoch: Some(epoch),
+                    epoch_slot: Some(epoch_slot),
+                });
+                // the sink stopped so there is nobody left to send events to
+                if event_tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+        if let Some(PointArg(start_slot, start_hash)) = start.filter(|_| skipping) {
+            panic!(
+                "Intersection point {} at slot {} not found in the block files",
+                start_hash, start_slot
+            );
+        }
+    });
+
+    Ok((vec![handle], event_rx))
+}
+
+fn list_files(dir: &str, filter: impl Fn(&Path) -> bool) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory {}: {}", dir, e))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|path| path.is_file() && filter(path))
+        .collect::<Vec<_>>();
+    // note: files have to be named so they sort in chain order (ex: ImmutableDB's 00000.chunk)
+    files.sort();
+    Ok(files)
+}
+
+// files with the .hex extension contain hex-encoded CBOR instead of raw bytes
+fn read_file(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let content = fs::read(path)?;
+    match path.extension() {
+        Some(ext) if ext == "hex" => Ok(hex::decode(String::from_utf8(content)?.trim())?),
+        _ => Ok(content),
+    }
+}
+
+/// Slot of a block read from its header without decoding the rest of the block
+fn peek_slot(cbor: &[u8]) -> Result<u64, minicbor::decode::Error> {
+    let mut decoder = minicbor::Decoder::new(cbor);
+    // [era tag, [header, ...]]
+    decoder.array()?;
+    let era = decoder.u16()?;
+    decoder.array()?;
+    decoder.array()?;
+    match era {
+        // Byron header: [protocol magic, prev hash, body proof, consensus data, extra data]
+        0 | 1 => {
+            for _ in 0..3 {
+                decoder.skip()?;
+            }
+            decoder.array()?;
+            // same absolute slot as pallas, which assumes the Byron epoch length of mainnet
+            match era {
+                // epoch boundary block: consensus data is [epoch, difficulty]
+                0 => Ok(decoder.u64()? * BYRON_EPOCH_LENGTH),
+                // consensus data is [[epoch, slot], ...]
+                _ => {
+                    decoder.array()?;
+                    Ok(decoder.u64()? * BYRON_EPOCH_LENGTH + decoder.u64()?)
+                }
+            }
+        }
+        // Shelley & later header: [[block number, slot, ...], signature]
+        _ => {
+            decoder.array()?;
+            decoder.u64()?;
+            decoder.u64()
+        }
+    }
+}
+
+/// A file may contain multiple blocks one after the other (ex: ImmutableDB chunks)
+fn split_blocks(content: &[u8]) -> anyhow::Result<Vec<&[u8]>> {
+    let mut blocks = vec![];
+    let mut decoder = minicbor::Decoder::new(content);
+    while decoder.position() < content.len() {
+        let start = decoder.position();
+        decoder.skip().map_err(|e| anyhow!("{}", e))?;
+        blocks.push(&content[start..decoder.position()]);
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // [era tag, [header, ...]] with the given header fields
+    fn block(era: u16, write_header: impl Fn(&mut minicbor::Encoder<Vec<u8>>)) -> Vec<u8> {
+        let mut encoder = minicbor::Encoder::new(Vec::new());
+        encoder.array(2).unwrap().u16(era).unwrap();
+        encoder.array(1).unwrap();
+        write_header(&mut encoder);
+        encoder.into_writer()
+    }
+
+    // Byron header: [protocol magic, prev hash, body proof, consensus data, extra data]
+    fn byron_block(era: u16, consensus_data: &[u64]) -> Vec<u8> {
+        block(era, |encoder| {
+            encoder.array(5).unwrap();
+            encoder.u32(764824073).unwrap();
+            encoder.bytes(&[0; 32]).unwrap();
+            encoder.null().unwrap();
+            encoder.array(2).unwrap();
+            match consensus_data {
+                [epoch, difficulty] => encoder.u64(*epoch).unwrap().u64(*difficulty).unwrap(),
+                [epoch, slot, difficulty] => encoder
+                    .array(2)
+                    .unwrap()
+                    .u64(*epoch)
+                    .unwrap()
+                    .u64(*slot)
+                    .unwrap()
+                    .u64(*difficulty)
+                    .unwrap(),
+                _ => unreachable!(),
+            };
+            encoder.null().unwrap();
+        })
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let first = block(2, |encoder| {
+            encoder.null().unwrap();
+        });
+        let second = block(5, |encoder| {
+            encoder.bytes(&[1, 2, 3]).unwrap();
+        });
+        let content = [first.clone(), second.clone()].concat();
+
+        assert_eq!(
+            split_blocks(&content).unwrap(),
+            vec![&first[..], &second[..]]
+        );
+        assert!(split_blocks(&[]).unwrap().is_empty());
+        // truncated block
+        assert!(split_blocks(&content[..content.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn peeks_slots() {
+        // Shelley & later header: [[block number, slot, ...], signature]
+        let shelley = block(2, |encoder| {
+            encoder.array(2).unwrap();
+            encoder
+                .array(2)
+                .unwrap()
+                .u64(10)
+                .unwrap()
+                .u64(4492800)
+                .unwrap();
+            encoder.bytes(&[0; 64]).unwrap();
+        });
+        assert_eq!(peek_slot(&shelley).unwrap(), 4492800);
+        assert_eq!(peek_slot(&byron_block(0, &[3, 1000])).unwrap(), 3 * 21600);
+        assert_eq!(
+            peek_slot(&byron_block(1, &[3, 5, 1001])).unwrap(),
+            3 * 21600 + 5
+        );
+        assert!(peek_slot(&[0x80]).is_err());
+    }
+}
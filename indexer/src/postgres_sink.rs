@@ -1,103 +1,192 @@
-use oura::{
-    model::{BlockRecord, EventData},
-    pipelining::StageReceiver,
-    sources::PointArg,
-};
-use std::sync::{Arc, Mutex};
+use oura::sources::PointArg;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{mpsc::Receiver, Arc, Mutex};
 use tasks::{
     byron::byron_executor::process_byron_block, dsl::database_task::BlockGlobalInfo,
-    execution_plan::ExecutionPlan, multiera::multiera_executor::process_multiera_block,
+    era_common::insert_utxos, execution_plan::ExecutionPlan,
+    multiera::multiera_executor::process_multiera_block, time::TimeParams,
     utils::TaskPerfAggregator,
 };
 
 use crate::perf_aggregator::PerfAggregator;
-use crate::types::MultiEraBlock;
+use crate::types::{BlockEvent, CarpEvent, MultiEraBlock};
 use entity::{
     block::EraValue,
     prelude::*,
-    sea_orm::{prelude::*, ColumnTrait, DatabaseTransaction, TransactionTrait},
+    sea_orm::{
+        prelude::*, sea_query::Query, ColumnTrait, DatabaseTransaction, QueryOrder, Set,
+        TransactionTrait,
+    },
 };
 use migration::DbErr;
 
 pub struct Config<'a> {
     pub conn: &'a DatabaseConnection,
+    pub time_params: TimeParams,
+    // number of genesis delegates that have to propose the same parameter update
+    // (updateQuorum in the Shelley genesis file)
+    pub update_quorum: usize,
+    pub stop_at: Option<StopPoint>,
+    // blocks are only added once they are this many blocks deep
+    // so that rollbacks within this window never touch the database
+    pub confirmation_depth: usize,
+    // whether to keep the blocks that aren't deep enough yet in the UnconfirmedBlock table
+    pub expose_unconfirmed: bool,
+    // whether the source ends on its own (block files, recordings) instead of following the tip
+    // the blocks of a finite source can't be rolled back once it ends, so they are all final
+    pub finite_source: bool,
+}
+
+/// Point at which to stop syncing (the block matching it is the last one added)
+#[derive(Clone)]
+pub enum StopPoint {
+    Slot(u64),
+    BlockHash(String),
+    Epoch(u64),
+    /// stops before the first block of the era
+    /// note: checked when the block is added to the database since that's when it gets decoded
+    Era(EraValue),
+}
+
+impl StopPoint {
+    /// whether the block is past the stop point and so shouldn't be added
+    fn is_after(&self, block: &BlockEvent) -> bool {
+        match self {
+            StopPoint::Slot(slot) => block.slot > *slot,
+            StopPoint::Epoch(epoch) => block
+                .epoch
+                .map_or(false, |block_epoch| block_epoch > *epoch),
+            StopPoint::BlockHash(_) | StopPoint::Era(_) => false,
+        }
+    }
+
+    /// whether the block is the last one to add
+    fn is_last(&self, block: &BlockEvent) -> bool {
+        match self {
+            StopPoint::BlockHash(hash) => block.hash == *hash,
+            _ => false,
+        }
+    }
+}
+
+pub enum SyncEnd {
+    SourceEnded,
+    StopPointReached,
+}
+
+/// Stats of the epoch currently being processed
+struct EpochStats {
+    last_epoch: i128,
+    epoch_start_time: std::time::Instant,
+    perf_aggregator: PerfAggregator,
+    task_perf_aggregator: Arc<Mutex<TaskPerfAggregator>>,
 }
 
 impl<'a> Config<'a> {
     pub async fn start(
         &self,
-        input: StageReceiver,
+        input: Receiver<CarpEvent>,
         exec_plan: Arc<ExecutionPlan>,
         initial_point: Option<&PointArg>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<SyncEnd> {
         tracing::info!("{}", "Starting to process blocks");
         let mut expected_rollback = initial_point;
 
-        let mut last_epoch: i128 = -1;
-        let mut epoch_start_time = std::time::Instant::now();
-        let mut perf_aggregator = PerfAggregator::new();
-        let mut task_perf_aggregator = Arc::new(Mutex::new(TaskPerfAggregator::default()));
+        let mut stats = EpochStats {
+            last_epoch: -1,
+            epoch_start_time: std::time::Instant::now(),
+            perf_aggregator: PerfAggregator::new(),
+            task_perf_aggregator: Arc::new(Mutex::new(TaskPerfAggregator::default())),
+        };
+
+        // blocks that aren't confirmation_depth deep yet (oldest first)
+        let mut volatile_blocks = VecDeque::<BlockEvent>::new();
+        // blocks left by a previous run may have been rolled back while Carp was stopped
+        UnconfirmedBlock::delete_many().exec(self.conn).await?;
 
         loop {
             let event_fetch_start = std::time::Instant::now();
-            let event = input.recv()?;
-            perf_aggregator.block_fetch += event_fetch_start.elapsed();
-
-            match &event.data {
-                EventData::Block(block_record) => {
-                    match block_record.epoch {
-                        Some(epoch) if epoch as i128 > last_epoch => {
-                            let epoch_duration = epoch_start_time.elapsed();
-                            perf_aggregator.set_overhead(
-                                &epoch_duration,
-                                &task_perf_aggregator.lock().unwrap().get_total(),
-                            );
+            let event = match input.recv() {
+                Ok(event) => event,
+                Err(_) => {
+                    // note: errors of the source itself are reported when joining its threads
+                    tracing::info!("{}", "Source has no more blocks");
+                    if !self.finite_source {
+                        // a live source also stops when the node disconnects or its thread fails
+                        // so the buffered blocks may still be on a fork
+                        self.drop_unconfirmed_blocks(&mut volatile_blocks).await?;
+                        return Ok(SyncEnd::SourceEnded);
+                    }
+                    // no rollback can happen anymore so the remaining blocks are final
+                    let confirmed = volatile_blocks.drain(..).collect();
+                    if self
+                        .commit_blocks(confirmed, None, &exec_plan, &mut stats)
+                        .await?
+                    {
+                        return Ok(SyncEnd::StopPointReached);
+                    }
+                    return Ok(SyncEnd::SourceEnded);
+                }
+            };
+            stats.perf_aggregator.block_fetch += event_fetch_start.elapsed();
 
-                            // skip posting stats if last_epoch == -1 (went application just launched)
-                            if last_epoch >= 0 {
-                                tracing::info!(
-                                    "Finished processing epoch {} after {:?}s (+{:?}s)",
-                                    last_epoch,
-                                    epoch_duration
-                                        .checked_sub(perf_aggregator.block_fetch)
-                                        .unwrap_or(std::time::Duration::new(0, 0))
-                                        .as_secs(),
-                                    perf_aggregator.block_fetch.as_secs()
-                                );
-
-                                tracing::trace!(
-                                    "Epoch non-task time spent:\n{:#?}\nEpoch task-wise time spent:\n{:#?}",
-                                    perf_aggregator,
-                                    task_perf_aggregator.lock().unwrap()
-                                );
-                            }
-                            epoch_start_time = std::time::Instant::now();
-                            perf_aggregator = PerfAggregator::new();
-                            task_perf_aggregator =
-                                Arc::new(Mutex::new(TaskPerfAggregator::default()));
+            match &event {
+                CarpEvent::Block(block_record) => {
+                    let stop_before = self
+                        .stop_at
+                        .as_ref()
+                        .map_or(false, |stop_at| stop_at.is_after(block_record));
+                    let stop_after = self
+                        .stop_at
+                        .as_ref()
+                        .map_or(false, |stop_at| stop_at.is_last(block_record));
 
-                            tracing::info!(
-                                "Starting epoch {} at block #{} ({})",
-                                epoch,
-                                block_record.number,
-                                block_record.hash
-                            );
-                            last_epoch = epoch as i128;
-                        }
-                        _ => (),
+                    let stop = stop_before || stop_after;
+
+                    if !stop_before {
+                        volatile_blocks.push_back(block_record.clone());
+                    }
+                    let confirmed_count = match stop && self.finite_source {
+                        // no rollback can happen anymore so the remaining blocks are final
+                        true => volatile_blocks.len(),
+                        false => volatile_blocks
+                            .len()
+                            .saturating_sub(self.confirmation_depth),
                     };
-                    self.conn
-                        .transaction::<_, (), DbErr>(|txn| {
-                            Box::pin(insert_block(
-                                block_record.clone(),
-                                txn,
-                                exec_plan.clone(),
-                                task_perf_aggregator.clone(),
-                            ))
-                        })
-                        .await?;
+                    let confirmed = volatile_blocks.drain(..confirmed_count).collect();
+                    // the new block is only left in the buffer if it isn't deep enough yet
+                    let unconfirmed = volatile_blocks
+                        .back()
+                        .filter(|block| !stop && block.hash == block_record.hash);
+                    if self
+                        .commit_blocks(confirmed, unconfirmed, &exec_plan, &mut stats)
+                        .await?
+                    {
+                        return Ok(SyncEnd::StopPointReached);
+                    }
+                    // with a live source, the sync stops at the last block that is already confirmed
+                    if stop {
+                        self.drop_unconfirmed_blocks(&mut volatile_blocks).await?;
+                    }
+
+                    if stop_before {
+                        tracing::info!(
+                            "Stopping before block #{} ({})",
+                            block_record.number,
+                            block_record.hash
+                        );
+                        return Ok(SyncEnd::StopPointReached);
+                    }
+                    if stop_after {
+                        tracing::info!(
+                            "Stopping after block #{} ({})",
+                            block_record.number,
+                            block_record.hash
+                        );
+                        return Ok(SyncEnd::StopPointReached);
+                    }
                 }
-                EventData::RollBack {
+                CarpEvent::RollBack {
                     block_slot,
                     block_hash,
                 } => {
@@ -120,6 +209,29 @@ impl<'a> Config<'a> {
                     };
                     let rollback_start = std::time::Instant::now();
 
+                    // rollbacks within the unconfirmed blocks never reach the database
+                    if let Some(index) = volatile_blocks
+                        .iter()
+                        .position(|block| block.hash == *block_hash)
+                    {
+                        let rolled_back = volatile_blocks.split_off(index + 1);
+                        if self.expose_unconfirmed && !rolled_back.is_empty() {
+                            UnconfirmedBlock::delete_many()
+                                .filter(
+                                    UnconfirmedBlockColumn::Hash.is_in(
+                                        rolled_back
+                                            .iter()
+                                            .map(|block| hex::decode(&block.hash).unwrap()),
+                                    ),
+                                )
+                                .exec(self.conn)
+                                .await?;
+                        }
+                        stats.perf_aggregator.rollback += rollback_start.elapsed();
+                        continue;
+                    }
+                    volatile_blocks.clear();
+
                     let point = Block::find()
                         .filter(BlockColumn::Hash.eq(hex::decode(block_hash).unwrap()))
                         .one(self.conn)
@@ -133,21 +245,226 @@ impl<'a> Config<'a> {
                                 "Rollback destination did not exist. Maybe you're stuck on a fork?"
                             );
                             }
+                            UnconfirmedBlock::delete_many().exec(self.conn).await?;
                         }
                         Some(point) => {
-                            Block::delete_many()
-                                .filter(BlockColumn::Id.gt(point.id))
-                                .exec(self.conn)
+                            let restore_utxos = exec_plan.0.contains_key("ByronUtxoTask")
+                                || exec_plan.0.contains_key("MultieraUtxoTask");
+                            let point_id = point.id;
+                            self.conn
+                                .transaction::<_, (), DbErr>(|txn| {
+                                    Box::pin(async move {
+                                        rollback_to(txn, point_id, restore_utxos).await?;
+                                        // every unconfirmed block was built on top of the rolled back blocks
+                                        UnconfirmedBlock::delete_many().exec(txn).await?;
+                                        Ok(())
+                                    })
+                                })
                                 .await?;
+                            // the next block may be in an epoch that was rolled back
+                            // so it has to be treated as the start of its epoch
+                            stats.last_epoch = -1;
                         }
                     }
 
-                    perf_aggregator.rollback += rollback_start.elapsed();
+                    stats.perf_aggregator.rollback += rollback_start.elapsed();
                 }
-                _ => (),
             }
         }
     }
+
+    /// Discards the blocks that aren't deep enough since they will never be confirmed
+    async fn drop_unconfirmed_blocks(
+        &self,
+        volatile_blocks: &mut VecDeque<BlockEvent>,
+    ) -> anyhow::Result<()> {
+        if !volatile_blocks.is_empty() {
+            tracing::info!(
+                "Dropping {} blocks that aren't confirmation_depth deep",
+                volatile_blocks.len()
+            );
+            volatile_blocks.clear();
+        }
+        UnconfirmedBlock::delete_many().exec(self.conn).await?;
+        Ok(())
+    }
+
+    /// Adds the confirmed blocks to the database
+    /// The UnconfirmedBlock table is updated in the same transaction so that it never overlaps with the Block table
+    /// Returns whether a block of the era to stop at was reached (in which case it isn't added)
+    async fn commit_blocks(
+        &self,
+        confirmed: Vec<BlockEvent>,
+        // block that was just received but isn't deep enough yet
+        unconfirmed: Option<&BlockEvent>,
+        exec_plan: &Arc<ExecutionPlan>,
+        stats: &mut EpochStats,
+    ) -> anyhow::Result<bool> {
+        let unconfirmed = unconfirmed
+            .filter(|_| self.expose_unconfirmed)
+            .map(to_unconfirmed_model);
+        if confirmed.is_empty() && unconfirmed.is_none() {
+            return Ok(false);
+        }
+        let confirmed_hashes = match self.expose_unconfirmed {
+            true => confirmed
+                .iter()
+                .map(|block| hex::decode(&block.hash).unwrap())
+                .collect(),
+            false => vec![],
+        };
+
+        let mut to_insert = Vec::with_capacity(confirmed.len());
+        for block_record in confirmed {
+            let is_epoch_start = match block_record.epoch {
+                Some(epoch) if epoch as i128 > stats.last_epoch => {
+                    let epoch_duration = stats.epoch_start_time.elapsed();
+                    stats.perf_aggregator.set_overhead(
+                        &epoch_duration,
+                        &stats.task_perf_aggregator.lock().unwrap().get_total(),
+                    );
+
+                    // skip posting stats if last_epoch == -1 (went application just launched)
+                    if stats.last_epoch >= 0 {
+                        tracing::info!(
+                            "Finished processing epoch {} after {:?}s (+{:?}s)",
+                            stats.last_epoch,
+                            epoch_duration
+                                .checked_sub(stats.perf_aggregator.block_fetch)
+                                .unwrap_or(std::time::Duration::new(0, 0))
+                                .as_secs(),
+                            stats.perf_aggregator.block_fetch.as_secs()
+                        );
+
+                        tracing::trace!(
+                            "Epoch non-task time spent:\n{:#?}\nEpoch task-wise time spent:\n{:#?}",
+                            stats.perf_aggregator,
+                            stats.task_perf_aggregator.lock().unwrap()
+                        );
+                    }
+                    stats.epoch_start_time = std::time::Instant::now();
+                    stats.perf_aggregator = PerfAggregator::new();
+                    stats.task_perf_aggregator =
+                        Arc::new(Mutex::new(TaskPerfAggregator::default()));
+
+                    tracing::info!(
+                        "Starting epoch {} at block #{} ({})",
+                        epoch,
+                        block_record.number,
+                        block_record.hash
+                    );
+                    stats.last_epoch = epoch as i128;
+                    true
+                }
+                _ => false,
+            };
+            to_insert.push((
+                block_record,
+                is_epoch_start,
+                stats.task_perf_aggregator.clone(),
+            ));
+        }
+
+        let stop_era = match self.stop_at {
+            Some(StopPoint::Era(era)) => Some(era),
+            _ => None,
+        };
+        let time_params = self.time_params;
+        let update_quorum = self.update_quorum;
+        let exec_plan = exec_plan.clone();
+        let stop_era_reached = self
+            .conn
+            .transaction::<_, bool, DbErr>(|txn| {
+                Box::pin(async move {
+                    for (block_record, is_epoch_start, task_perf_aggregator) in to_insert {
+                        let added = insert_block(
+                            block_record,
+                            is_epoch_start,
+                            stop_era,
+                            time_params,
+                            update_quorum,
+                            txn,
+                            exec_plan.clone(),
+                            task_perf_aggregator,
+                        )
+                        .await?;
+                        if !added {
+                            tracing::info!(
+                                "Stopping before the first block of the {:?} era",
+                                stop_era.unwrap()
+                            );
+                            // the following blocks are past the stop point as well
+                            UnconfirmedBlock::delete_many().exec(txn).await?;
+                            return Ok(true);
+                        }
+                    }
+                    if !confirmed_hashes.is_empty() {
+                        UnconfirmedBlock::delete_many()
+                            .filter(UnconfirmedBlockColumn::Hash.is_in(confirmed_hashes))
+                            .exec(txn)
+                            .await?;
+                    }
+                    if let Some(unconfirmed) = unconfirmed {
+                        unconfirmed.insert(txn).await?;
+                    }
+                    Ok(false)
+                })
+            })
+            .await?;
+        Ok(stop_era_reached)
+    }
+}
+
+fn to_unconfirmed_model(block: &BlockEvent) -> UnconfirmedBlockActiveModel {
+    UnconfirmedBlockActiveModel {
+        hash: Set(hex::decode(&block.hash).unwrap()),
+        height: Set(block.number as i32),
+        epoch: Set(block.epoch.map(|epoch| epoch as i32)),
+        slot: Set(block.slot as i32),
+        payload: Set(hex::decode(&block.cbor_hex).unwrap()),
+        ..Default::default()
+    }
+}
+
+async fn rollback_to(
+    txn: &DatabaseTransaction,
+    block_id: i32,
+    restore_utxos: bool,
+) -> Result<(), DbErr> {
+    if restore_utxos {
+        // outputs created in the rolled back blocks are removed from the UTXO set by the cascade
+        // but outputs from earlier blocks that were spent by the rolled back blocks have to be restored
+        let spent_outputs = TransactionOutput::find()
+            .inner_join(Transaction)
+            .filter(TransactionColumn::BlockId.lte(block_id))
+            .filter(
+                TransactionOutputColumn::Id.in_subquery(
+                    Query::select()
+                        .column(TransactionInputColumn::UtxoId)
+                        .from(TransactionInput)
+                        .and_where(
+                            TransactionInputColumn::TxId.in_subquery(
+                                Query::select()
+                                    .column(TransactionColumn::Id)
+                                    .from(Transaction)
+                                    .and_where(TransactionColumn::BlockId.gt(block_id))
+                                    .to_owned(),
+                            ),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .all(txn)
+            .await?;
+        insert_utxos(&spent_outputs, txn).await?;
+    }
+
+    Block::delete_many()
+        .filter(BlockColumn::Id.gt(block_id))
+        .exec(txn)
+        .await?;
+
+    Ok(())
 }
 
 fn to_era_value(x: pallas::ledger::traverse::Era) -> EraValue {
@@ -162,23 +479,35 @@ fn to_era_value(x: pallas::ledger::traverse::Era) -> EraValue {
     }
 }
 
+/// Returns whether the block was added (blocks of the era to stop at or later aren't)
 async fn insert_block(
-    block_record: BlockRecord,
+    block_record: BlockEvent,
+    // whether this is the first block of its epoch since the sync started (or rolled back)
+    is_epoch_start: bool,
+    stop_era: Option<EraValue>,
+    time_params: TimeParams,
+    update_quorum: usize,
     txn: &DatabaseTransaction,
     exec_plan: Arc<ExecutionPlan>,
     task_perf_aggregator: Arc<Mutex<TaskPerfAggregator>>,
-) -> Result<(), DbErr> {
+) -> Result<bool, DbErr> {
     let mut perf_aggregator = PerfAggregator::new();
 
     let block_parse_counter = std::time::Instant::now();
 
-    let block_payload = hex::decode(block_record.cbor_hex.as_ref().unwrap()).unwrap();
+    let block_payload = hex::decode(&block_record.cbor_hex).unwrap();
     let multi_block = MultiEraBlock::decode(&block_payload).unwrap();
 
+    let era = to_era_value(multi_block.era());
+    if stop_era.map_or(false, |stop_era| era >= stop_era) {
+        return Ok(false);
+    }
+
     let block_global_info = BlockGlobalInfo {
-        era: to_era_value(multi_block.era()),
+        era,
         epoch: block_record.epoch,
         epoch_slot: block_record.epoch_slot,
+        time: time_params.slot_to_time(multi_block.slot()),
     };
 
     perf_aggregator.block_parse += block_parse_counter.elapsed();
@@ -187,11 +516,7 @@ async fn insert_block(
         pallas::ledger::traverse::Era::Byron => {
             process_byron_block(
                 txn,
-                (
-                    &block_record.cbor_hex.unwrap(),
-                    &multi_block,
-                    &block_global_info,
-                ),
+                (&block_record.cbor_hex, &multi_block, &block_global_info),
                 &exec_plan,
                 task_perf_aggregator.clone(),
             )
@@ -200,17 +525,122 @@ async fn insert_block(
         _ => {
             process_multiera_block(
                 txn,
-                (
-                    &block_record.cbor_hex.unwrap(),
-                    &multi_block,
-                    &block_global_info,
-                ),
+                (&block_record.cbor_hex, &multi_block, &block_global_info),
                 &exec_plan,
                 task_perf_aggregator.clone(),
             )
-            .await?
+            .await?;
+
+            if is_epoch_start && exec_plan.0.contains_key("MultieraParamUpdateTask") {
+                update_epoch_params(
+                    txn,
+                    &block_record.hash,
+                    block_record.epoch.unwrap() as i32,
+                    update_quorum,
+                )
+                .await?;
+            }
         }
     }
 
+    Ok(true)
+}
+
+// parameters not changed by any of the applied proposals keep the value of the previous epoch
+macro_rules! merge_params {
+    ($previous:expr, $proposals:expr, $($field:ident),+) => {
+        EpochParamsActiveModel {
+            $($field: Set(
+                $proposals
+                    .iter()
+                    .rev()
+                    .find_map(|proposal| proposal.$field)
+                    .or($previous.$field),
+            ),)+
+            ..Default::default()
+        }
+    };
+}
+
+/// Derives the parameters in effect for the epoch from the proposals made for the previous one
+/// Note: the parameters may already exist if the sync restarted in the middle of the epoch
+/// Note: nothing is derived without the epoch 0 parameters from the Shelley genesis
+/// since the parameters that no proposal changes would be unknown
+async fn update_epoch_params(
+    txn: &DatabaseTransaction,
+    block_hash: &str,
+    epoch: i32,
+    update_quorum: usize,
+) -> Result<(), DbErr> {
+    if EpochParams::find_by_id(epoch).one(txn).await?.is_some() {
+        return Ok(());
+    }
+
+    let previous = match EpochParams::find()
+        .filter(EpochParamsColumn::Epoch.lt(epoch))
+        .order_by_desc(EpochParamsColumn::Epoch)
+        .one(txn)
+        .await?
+    {
+        Some(previous) => previous,
+        None => {
+            tracing::warn!(
+                "Skipping the parameters of epoch {} because the Shelley genesis parameters are missing",
+                epoch
+            );
+            return Ok(());
+        }
+    };
+    let block = Block::find()
+        .filter(BlockColumn::Hash.eq(hex::decode(block_hash).unwrap()))
+        .one(txn)
+        .await?
+        .unwrap();
+    let proposals = ParamProposal::find()
+        .filter(ParamProposalColumn::Epoch.eq(epoch - 1))
+        .order_by_asc(ParamProposalColumn::Id)
+        .all(txn)
+        .await?;
+
+    // a genesis delegate can replace its proposal until the end of the epoch
+    let mut latest_proposals = BTreeMap::<&Vec<u8>, &ParamProposalModel>::default();
+    for proposal in proposals.iter() {
+        latest_proposals.insert(&proposal.genesis_key, proposal);
+    }
+    // an update is only applied if enough delegates proposed the exact same one
+    let mut votes = BTreeMap::<&Vec<u8>, Vec<&ParamProposalModel>>::default();
+    for proposal in latest_proposals.values() {
+        votes.entry(&proposal.payload).or_default().push(proposal);
+    }
+    let mut applied = votes
+        .values()
+        .filter(|voters| voters.len() >= update_quorum)
+        .map(|voters| voters[0])
+        .collect::<Vec<_>>();
+    applied.sort_by_key(|proposal| proposal.id);
+
+    let mut params = merge_params!(
+        previous,
+        applied,
+        min_fee_a,
+        min_fee_b,
+        max_block_body_size,
+        max_tx_size,
+        max_block_header_size,
+        key_deposit,
+        pool_deposit,
+        max_epoch,
+        optimal_pool_count,
+        protocol_major,
+        protocol_minor,
+        min_pool_cost,
+        max_value_size,
+        collateral_percentage,
+        max_collateral_inputs
+    );
+    params.epoch = Set(epoch);
+    params.block_id = Set(block.id);
+    params.insert(txn).await?;
+
     Ok(())
 }
@@ -0,0 +1,267 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    sync::mpsc::{sync_channel, Receiver},
+    thread::JoinHandle,
+};
+
+use anyhow::anyhow;
+use oura::sources::{IntersectArg, PointArg};
+use pallas::codec::minicbor::{self, data::Type};
+use pallas::ledger::traverse::MultiEraBlock;
+
+use crate::types::{BlockEvent, CarpEvent};
+
+// how many events can be read ahead of the sink
+const READ_AHEAD: usize = 1000;
+
+// A recording is a sequence of events, each one prefixed by its length (u32 little-endian)
+// Events are CBOR arrays:
+// - block: [0, block bytes, number, epoch / null, epoch_slot / null]
+// - rollback: [1, slot, hash bytes]
+// note: block hashes & slots aren't stored since they can be recomputed from the block
+const BLOCK_TAG: u8 = 0;
+const ROLLBACK_TAG: u8 = 1;
+
+fn encode_event(event: &CarpEvent) -> Vec<u8> {
+    let mut encoder = minicbor::Encoder::new(Vec::new());
+    // note: writing to a Vec can't fail
+    match event {
+        CarpEvent::Block(block) => {
+            encoder
+                .array(5)
+                .unwrap()
+                .u8(BLOCK_TAG)
+                .unwrap()
+                .bytes(&hex::decode(&block.cbor_hex).unwrap())
+                .unwrap()
+                .u64(block.number)
+                .unwrap();
+            for value in [block.epoch, block.epoch_slot] {
+                match value {
+                    Some(value) => encoder.u64(value).unwrap(),
+                    None => encoder.null().unwrap(),
+                };
+            }
+        }
+        CarpEvent::RollBack {
+            block_slot,
+            block_hash,
+        } => {
+            encoder
+                .array(3)
+                .unwrap()
+                .u8(ROLLBACK_TAG)
+                .unwrap()
+                .u64(*block_slot)
+                .unwrap()
+                .bytes(&hex::decode(block_hash).unwrap())
+                .unwrap();
+        }
+    };
+    encoder.into_writer()
+}
+
+fn decode_optional_u64(
+    decoder: &mut minicbor::Decoder<'_>,
+) -> Result<Option<u64>, minicbor::decode::Error> {
+    match decoder.datatype()? {
+        Type::Null => decoder.null().map(|_| None),
+        _ => decoder.u64().map(Some),
+    }
+}
+
+fn decode_event(bytes: &[u8]) -> Result<CarpEvent, minicbor::decode::Error> {
+    let mut decoder = minicbor::Decoder::new(bytes);
+
+    decoder.array()?;
+    match decoder.u8()? {
+        BLOCK_TAG => {
+            let cbor = decoder.bytes()?;
+            let block = MultiEraBlock::decode(cbor)
+                .map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            Ok(CarpEvent::Block(BlockEvent {
+                cbor_hex: hex::encode(cbor),
+                hash: hex::encode(block.hash()),
+                slot: block.slot(),
+                number: decoder.u64()?,
+                epoch: decode_optional_u64(&mut decoder)?,
+                epoch_slot: decode_optional_u64(&mut decoder)?,
+            }))
+        }
+        ROLLBACK_TAG => Ok(CarpEvent::RollBack {
+            block_slot: decoder.u64()?,
+            block_hash: hex::encode(decoder.bytes()?),
+        }),
+        tag => Err(minicbor::decode::Error::message(format!(
+            "unknown event tag {}",
+            tag
+        ))),
+    }
+}
+
+/// Writes every event of the source to a file before passing it along to the sink
+pub fn record(
+    input: Receiver<CarpEvent>,
+    path: &str,
+) -> anyhow::Result<(JoinHandle<()>, Receiver<CarpEvent>)> {
+    let file = File::create(path).map_err(|e| anyhow!("Failed to create {}: {}", path, e))?;
+    tracing::info!("Recording events to {}", path);
+
+    let (event_tx, event_rx) = sync_channel(READ_AHEAD);
+    let handle = std::thread::spawn(move || {
+        let mut writer = BufWriter::new(file);
+        for event in input.iter() {
+            let bytes = encode_event(&event);
+            writer
+                .write_all(&(bytes.len() as u32).to_le_bytes())
+                .and_then(|_| writer.write_all(&bytes))
+                // flush so the recording is usable even if Carp crashes on this event
+                .and_then(|_| writer.flush())
+                .expect("Failed to write to the recording");
+            // the sink stopped so there is nobody left to send events to
+            if event_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok((handle, event_rx))
+}
+
+/// Feeds the events of a recording to the sink
+/// Events up to the intersection are skipped, so the recording has to contain either the last
+/// block of the database or the rollback to it that the node sends when the sync starts
+pub fn replay_bootstrap(
+    intersect: IntersectArg,
+    path: &str,
+) -> anyhow::Result<(Vec<JoinHandle<()>>, Receiver<CarpEvent>)> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open {}: {}", path, e))?;
+    tracing::info!("Replaying events from {}", path);
+
+    // blocks up to the intersection are already in the database
+    let start = match intersect {
+        IntersectArg::Fallbacks(points) => points.first().cloned(),
+        IntersectArg::Point(point) => Some(point),
+        _ => None,
+    };
+
+    let (event_tx, event_rx) = sync_channel(READ_AHEAD);
+    let handle = std::thread::spawn(move || {
+        let mut reader = BufReader::new(file);
+        let mut len_bytes = [0u8; 4];
+        let mut count = 0;
+        let mut skipping = start.is_some();
+        loop {
+            match reader.read_exact(&mut len_bytes) {
+                Ok(()) => (),
+                // end of the recording
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(e) => panic!("Failed to read the recording: {}", e),
+            }
+            let mut bytes = vec![0u8; u32::from_le_bytes(len_bytes) as usize];
+            reader
+                .read_exact(&mut bytes)
+                .expect("Recording ended in the middle of an event");
+            let event = decode_event(&bytes)
+                .unwrap_or_else(|e| panic!("Failed to decode event #{}: {}", count, e));
+            count += 1;
+            if let Some(PointArg(start_slot, start_hash)) = start.as_ref().filter(|_| skipping) {
+                let hash = match &event {
+                    CarpEvent::Block(block) if block.slot > *start_slot => panic!(
+                        "Intersection point {} at slot {} not found in the recording",
+                        start_hash, start_slot
+                    ),
+                    CarpEvent::Block(BlockEvent { hash, .. }) => hash,
+                    CarpEvent::RollBack { block_hash, .. } => block_hash,
+                };
+                skipping = hash != start_hash;
+                continue;
+            }
+            // the sink stopped so there is nobody left to send events to
+            if event_tx.send(event).is_err() {
+                return;
+            }
+        }
+        if let Some(PointArg(start_slot, start_hash)) = start.filter(|_| skipping) {
+            panic!(
+                "Intersection point {} at slot {} not found in the recording",
+                start_hash, start_slot
+            );
+        }
+        tracing::info!("Replayed {} events", count);
+    });
+
+    Ok((vec![handle], event_rx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Byron epoch boundary block: [0, [header, body, extra]]
+    // header: [protocol magic, prev hash, body proof, [epoch, [difficulty]], [attributes]]
+    fn boundary_block(epoch: u64) -> Vec<u8> {
+        let mut encoder = minicbor::Encoder::new(Vec::new());
+        encoder.array(2).unwrap().u8(0).unwrap().array(3).unwrap();
+        encoder
+            .array(5)
+            .unwrap()
+            .u32(764824073)
+            .unwrap()
+            .bytes(&[1; 32])
+            .unwrap()
+            .bytes(&[2; 32])
+            .unwrap();
+        encoder
+            .array(2)
+            .unwrap()
+            .u64(epoch)
+            .unwrap()
+            .array(1)
+            .unwrap()
+            .u64(epoch * 21600)
+            .unwrap();
+        encoder.array(1).unwrap().map(0).unwrap();
+        encoder.array(0).unwrap();
+        encoder.array(1).unwrap().map(0).unwrap();
+        encoder.into_writer()
+    }
+
+    fn block_event(epoch: Option<u64>) -> BlockEvent {
+        let cbor = boundary_block(1);
+        let block = MultiEraBlock::decode(&cbor).unwrap();
+        BlockEvent {
+            cbor_hex: hex::encode(&cbor),
+            hash: hex::encode(block.hash()),
+            number: block.number(),
+            slot: block.slot(),
+            epoch,
+            epoch_slot: epoch.map(|_| 0),
+        }
+    }
+
+    #[test]
+    fn round_trips_blocks() {
+        for event in [block_event(Some(1)), block_event(None)] {
+            let event = CarpEvent::Block(event);
+            assert_eq!(decode_event(&encode_event(&event)).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn round_trips_rollbacks() {
+        let event = CarpEvent::RollBack {
+            block_slot: 4492800,
+            block_hash: hex::encode([3; 32]),
+        };
+        assert_eq!(decode_event(&encode_event(&event)).unwrap(), event);
+    }
+
+    #[test]
+    fn rejects_unknown_events() {
+        let mut encoder = minicbor::Encoder::new(Vec::new());
+        encoder.array(1).unwrap().u8(2).unwrap();
+        assert!(decode_event(&encoder.into_writer()).is_err());
+    }
+}
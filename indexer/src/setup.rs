@@ -1,4 +1,7 @@
-use std::{str::FromStr, sync::Arc, thread::JoinHandle};
+use std::{
+    sync::{mpsc::Receiver, Arc},
+    thread::JoinHandle,
+};
 
 use anyhow::anyhow;
 
@@ -11,11 +14,32 @@ use entity::{
 use oura::{
     filters::selection::{self, Predicate},
     mapper,
-    pipelining::{FilterProvider, SourceProvider, StageReceiver},
-    sources::{n2c, AddressArg, BearerKind, IntersectArg, MagicArg, PointArg},
-    utils::{ChainWellKnownInfo, Utils, WithUtils},
+    model::EventData,
+    pipelining::{FilterProvider, SourceProvider},
+    sources::{n2c, n2n, AddressArg, BearerKind, IntersectArg, MagicArg, PointArg},
+    utils::{Utils, WithUtils},
 };
 
+use tasks::time::TimeParams;
+
+use crate::network::NetworkConfig;
+use crate::types::{BlockEvent, CarpEvent};
+
+/// Where blocks come from
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum Source {
+    /// node-to-client protocol over the Unix socket of a local node
+    N2c,
+    /// node-to-node protocol (chain-sync + block-fetch) over TCP to a relay
+    N2n,
+    /// directory of files containing block CBOR (no node required)
+    CborFiles,
+    /// directory of ImmutableDB chunk files of cardano-node (no node required)
+    ImmutableDb,
+    /// events previously written with --record (no node required)
+    Replay,
+}
+
 /// note: points are sorted from newest to oldest
 pub async fn get_latest_points(conn: &DatabaseConnection) -> anyhow::Result<Vec<PointArg>> {
     let points: Vec<PointArg> = Block::find()
@@ -68,13 +92,14 @@ pub async fn get_specific_point(
 
 pub fn oura_bootstrap(
     intersect: IntersectArg,
-    network: &str,
+    network: &NetworkConfig,
+    time_params: TimeParams,
+    source: Source,
     socket: String,
-) -> anyhow::Result<(Vec<JoinHandle<()>>, StageReceiver)> {
-    let magic = MagicArg::from_str(network).map_err(|_| anyhow!("magic arg failed"))?;
+) -> anyhow::Result<(Vec<JoinHandle<()>>, Receiver<CarpEvent>)> {
+    let magic = MagicArg(network.magic);
 
-    let well_known = ChainWellKnownInfo::try_from_magic(*magic)
-        .map_err(|_| anyhow!("chain well known info failed"))?;
+    let well_known = network.well_known_info(&time_params);
 
     let utils = Arc::new(Utils::new(well_known));
 
@@ -84,22 +109,6 @@ pub fn oura_bootstrap(
         ..Default::default()
     };
 
-    #[allow(deprecated)]
-    let source_config = n2c::Config {
-        address: AddressArg(BearerKind::Unix, socket),
-        // address: AddressArg(BearerKind::Tcp, socket),
-        magic: Some(magic),
-        well_known: None,
-        mapper,
-        since: None,
-        min_depth: 0,
-        intersect: Some(intersect),
-        retry_policy: None,
-        finalize: None, // TODO: configurable
-    };
-
-    let source_setup = WithUtils::new(source_config, utils);
-
     let check = Predicate::VariantIn(vec![String::from("Block"), String::from("Rollback")]);
 
     let filter_setup = selection::Config { check };
@@ -108,7 +117,48 @@ pub fn oura_bootstrap(
 
     tracing::info!("{}", "Attempting to connect to node...");
 
-    let (source_handle, source_rx) = source_setup.bootstrap().map_err(|e| {
+    // note: both sources provide the same events so the rest of the pipeline is identical
+    #[allow(deprecated)]
+    let source_result = match source {
+        Source::N2c => WithUtils::new(
+            n2c::Config {
+                address: AddressArg(BearerKind::Unix, socket),
+                magic: Some(magic),
+                well_known: None,
+                mapper,
+                since: None,
+                min_depth: 0,
+                intersect: Some(intersect),
+                retry_policy: None,
+                // stop points are handled by the sink (oura can only stop at a slot or a block hash)
+                finalize: None,
+            },
+            utils,
+        )
+        .bootstrap(),
+        // socket is the host:port of the relay
+        Source::N2n => WithUtils::new(
+            n2n::Config {
+                address: AddressArg(BearerKind::Tcp, socket),
+                magic: Some(magic),
+                well_known: None,
+                mapper,
+                since: None,
+                min_depth: 0,
+                intersect: Some(intersect),
+                retry_policy: None,
+                // stop points are handled by the sink (oura can only stop at a slot or a block hash)
+                finalize: None,
+            },
+            utils,
+        )
+        .bootstrap(),
+        Source::CborFiles | Source::ImmutableDb | Source::Replay => {
+            return Err(anyhow!("{:?} doesn't read blocks from a node", source))
+        }
+    };
+
+    let (source_handle, source_rx) = source_result.map_err(|e| {
         tracing::error!("{}", e);
         anyhow!("failed to bootstrap source. Are you sure cardano-node is running?")
     })?;
@@ -123,5 +173,40 @@ pub fn oura_bootstrap(
 
     handles.push(filter_handle);
 
-    Ok((handles, filter_rx))
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    let event_handle = std::thread::spawn(move || {
+        for event in filter_rx.iter() {
+            let event = match event.data {
+                EventData::Block(block_record) => {
+                    // note: epochs are computed from the time params instead of using oura's
+                    // so all sources agree on them
+                    let (epoch, epoch_slot) = time_params.slot_to_epoch(block_record.slot);
+                    CarpEvent::Block(BlockEvent {
+                        cbor_hex: block_record.cbor_hex.unwrap(),
+                        hash: block_record.hash,
+                        number: block_record.number,
+                        slot: block_record.slot,
+                        epoch: Some(epoch),
+                        epoch_slot: Some(epoch_slot),
+                    })
+                }
+                EventData::RollBack {
+                    block_slot,
+                    block_hash,
+                } => CarpEvent::RollBack {
+                    block_slot,
+                    block_hash,
+                },
+                _ => continue,
+            };
+            // the sink stopped so there is nobody left to send events to
+            if event_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    handles.push(event_handle);
+
+    Ok((handles, event_rx))
 }
@@ -1 +1,19 @@
 pub type MultiEraBlock<'b> = pallas::ledger::traverse::MultiEraBlock<'b>;
+
+/// A block to add to the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockEvent {
+    pub cbor_hex: String,
+    pub hash: String,
+    pub number: u64,
+    pub slot: u64,
+    pub epoch: Option<u64>,
+    pub epoch_slot: Option<u64>,
+}
+
+/// Chain events processed by the sink, regardless of where they come from (node, files, etc.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CarpEvent {
+    Block(BlockEvent),
+    RollBack { block_slot: u64, block_hash: String },
+}
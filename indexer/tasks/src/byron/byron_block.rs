@@ -42,6 +42,8 @@ async fn handle_block(
         height: Set(block.1.number() as i32),
         epoch: Set(block.1.header().as_byron().unwrap().consensus_data.0.epoch as i32),
         slot: Set(block.1.slot() as i32),
+        tx_count: Set(Some(block.1.txs().len() as i32)),
+        time: Set(Some(block.2.time as i64)),
         ..Default::default()
     };
 
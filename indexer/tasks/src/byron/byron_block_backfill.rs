@@ -0,0 +1,44 @@
+use crate::dsl::task_macro::*;
+use entity::sea_orm::sea_query::Expr;
+
+use super::byron_block::ByronBlockTask;
+use crate::config::EmptyConfig::EmptyConfig;
+
+carp_task! {
+  name ByronBlockBackfillTask;
+  configuration EmptyConfig;
+  doc "Fills in the transaction count and time of blocks that were added before these columns existed.
+       Only meant to be used with ByronBlockTask in readonly mode (see the block_backfill execution plan)";
+  era byron;
+  dependencies [ByronBlockTask];
+  read [byron_block];
+  write [];
+  should_add_task |_block, _properties| {
+    true
+  };
+  execute |previous_data, task| handle_backfill(
+      task.db_tx,
+      task.block,
+      previous_data.byron_block.as_ref().unwrap(),
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_backfill(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    database_block: &BlockModel,
+) -> Result<(), DbErr> {
+    Block::update_many()
+        .col_expr(
+            BlockColumn::TxCount,
+            Expr::value(block.1.txs().len() as i32),
+        )
+        .col_expr(BlockColumn::Time, Expr::value(block.2.time as i64))
+        .filter(BlockColumn::Id.eq(database_block.id))
+        .exec(db_tx)
+        .await?;
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use crate::dsl::task_macro::*;
-use pallas::ledger::primitives::byron::{self, TxIn};
+use pallas::ledger::primitives::{
+    byron::{self, TxIn, TxOut},
+    Fragment,
+};
 
 use super::byron_outputs::ByronOutputTask;
 use crate::config::EmptyConfig::EmptyConfig;
@@ -50,5 +53,33 @@ async fn handle_inputs(
     let input_to_output_map = crate::era_common::gen_input_to_output_map(&outputs_for_inputs);
     let result =
         crate::era_common::insert_inputs(&flattened_inputs, &input_to_output_map, db_tx).await?;
+    update_byron_fees(db_tx, block, byron_txs, &input_to_output_map).await?;
     Ok(result)
 }
+
+/// Byron txs don't specify their fee, so it has to be computed from the inputs they spend
+pub async fn update_byron_fees(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    byron_txs: &[TransactionModel],
+    input_to_output_map: &BTreeMap<&Vec<u8>, BTreeMap<i64, &TransactionOutputModel>>,
+) -> Result<(), DbErr> {
+    let mut fees = Vec::<(i64, u64)>::default();
+    for (tx, cardano_tx_in_db) in block.1.txs().iter().zip(byron_txs) {
+        let input_sum: u64 = tx
+            .inputs()
+            .iter()
+            .map(|input| {
+                let output_ref = input.output_ref();
+                let output =
+                    input_to_output_map[&output_ref.hash().to_vec()][&(output_ref.index() as i64)];
+                TxOut::decode_fragment(&output.payload).unwrap().amount
+            })
+            .sum();
+        let output_sum: u64 = tx.outputs().iter().map(|output| output.ada_amount()).sum();
+
+        fees.push((cardano_tx_in_db.id, input_sum - output_sum));
+    }
+
+    crate::era_common::update_transaction_fees(db_tx, &fees).await
+}
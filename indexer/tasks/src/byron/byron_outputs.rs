@@ -80,6 +80,7 @@ async fn insert_byron_outputs(
                         .id),
                     tx_id: Set(tx_id.id),
                     output_index: Set(output_index as i32),
+                    amount: Set(Some(output.amount as i64)),
                     ..Default::default()
                 },
             ),
@@ -56,8 +56,11 @@ async fn handle_tx(
                 hash: Set(tx.hash().to_vec()),
                 block_id: Set(database_block.id),
                 tx_index: Set(idx as i32),
+                size: Set(Some(tx_payload.len() as i32)),
                 payload: Set(tx_payload),
                 is_valid: Set(true), // always true in Byron
+                // note: Byron txs don't specify their fee explicitly
+                // so it gets filled in by ByronInputTask once the inputs are resolved
                 ..Default::default()
             }
         }))
@@ -0,0 +1,34 @@
+use super::byron_inputs::ByronInputTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::era_common::{delete_spent_utxos, insert_utxos};
+
+carp_task! {
+  name ByronUtxoTask;
+  configuration EmptyConfig;
+  doc "Keeps the live UTXO set in sync by adding the outputs and removing the spent inputs of the block";
+  era byron;
+  dependencies [ByronInputTask];
+  read [byron_outputs, byron_inputs];
+  write [];
+  should_add_task |block, _properties| {
+    !block.1.is_empty()
+  };
+  execute |previous_data, task| handle_utxos(
+      task.db_tx,
+      &previous_data.byron_outputs,
+      &previous_data.byron_inputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_utxos(
+    db_tx: &DatabaseTransaction,
+    outputs: &[TransactionOutputModel],
+    inputs: &[TransactionInputModel],
+) -> Result<(), DbErr> {
+    // note: outputs have to be added first since they may be spent within the same block
+    insert_utxos(outputs, db_tx).await?;
+    delete_spent_utxos(inputs, db_tx).await
+}
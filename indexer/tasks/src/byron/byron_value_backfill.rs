@@ -0,0 +1,67 @@
+use crate::dsl::task_macro::*;
+use entity::sea_orm::sea_query::Expr;
+
+use super::{byron_inputs::update_byron_fees, byron_txs::ByronTransactionTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::era_common::update_output_amounts;
+
+carp_task! {
+  name ByronValueBackfillTask;
+  configuration EmptyConfig;
+  doc "Fills in the fee, size and output amounts of transactions that were added before these columns existed.
+       Only meant to be used with ByronTransactionTask in readonly mode (see the value_backfill execution plan)";
+  era byron;
+  dependencies [ByronTransactionTask];
+  read [byron_txs];
+  write [];
+  should_add_task |block, _properties| {
+    !block.1.is_empty()
+  };
+  execute |previous_data, task| handle_backfill(
+      task.db_tx,
+      task.block,
+      previous_data.byron_txs.as_slice(),
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_backfill(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    byron_txs: &[TransactionModel],
+) -> Result<(), DbErr> {
+    let mut amounts = Vec::<(i64, usize, u64)>::default();
+    for (tx, cardano_tx_in_db) in block.1.txs().iter().zip(byron_txs) {
+        Transaction::update_many()
+            .col_expr(
+                TransactionColumn::Size,
+                Expr::value(cardano_tx_in_db.payload.len() as i32),
+            )
+            .filter(TransactionColumn::Id.eq(cardano_tx_in_db.id))
+            .exec(db_tx)
+            .await?;
+
+        for (idx, output) in tx.outputs().iter().enumerate() {
+            amounts.push((cardano_tx_in_db.id, idx, output.ada_amount()));
+        }
+    }
+    update_output_amounts(db_tx, &amounts).await?;
+
+    let flattened_inputs: Vec<(Vec<pallas::ledger::traverse::OutputRef>, i64)> = block
+        .1
+        .txs()
+        .iter()
+        .zip(byron_txs)
+        .map(|(tx, cardano_tx_in_db)| {
+            let inputs: Vec<pallas::ledger::traverse::OutputRef> =
+                tx.inputs().iter().map(|x| x.output_ref()).collect();
+
+            (inputs, cardano_tx_in_db.id)
+        })
+        .collect();
+    let outputs_for_inputs =
+        crate::era_common::get_outputs_for_inputs(&flattened_inputs, db_tx).await?;
+    let input_to_output_map = crate::era_common::gen_input_to_output_map(&outputs_for_inputs);
+    update_byron_fees(db_tx, block, byron_txs, &input_to_output_map).await
+}
@@ -1,6 +1,9 @@
 pub mod byron_address;
 pub mod byron_block;
+pub mod byron_block_backfill;
 pub mod byron_executor;
 pub mod byron_inputs;
 pub mod byron_outputs;
 pub mod byron_txs;
+pub mod byron_utxo;
+pub mod byron_value_backfill;
@@ -1,5 +1,5 @@
+use crate::genesis::genesis_files::GenesisFiles;
 use crate::utils::TaskPerfAggregator;
-use cardano_multiplatform_lib::genesis::byron::config::GenesisData;
 use entity::{block::EraValue, prelude::*, sea_orm::DatabaseTransaction};
 use pallas::ledger::traverse::MultiEraBlock;
 use shred::DispatcherBuilder;
@@ -10,6 +10,7 @@ pub struct BlockGlobalInfo {
     pub era: EraValue,
     pub epoch: Option<u64>,
     pub epoch_slot: Option<u64>,
+    pub time: u64, // unix timestamp (milliseconds) of the block slot
 }
 
 pub type BlockInfo<'a, BlockType> = (
@@ -59,7 +60,7 @@ pub enum TaskRegistryEntry {
 
 #[derive(Copy, Clone)]
 pub struct GenesisTaskRegistryEntry {
-    pub builder: &'static (dyn for<'a> TaskBuilder<'a, GenesisData> + Sync),
+    pub builder: &'static (dyn for<'a> TaskBuilder<'a, GenesisFiles> + Sync),
 }
 
 #[derive(Copy, Clone)]
@@ -1,4 +1,5 @@
 pub(crate) use super::execution_context::*;
+pub use crate::genesis::genesis_files::GenesisFiles;
 pub use crate::utils::find_task_registry_entry;
 pub use crate::{
     dsl::database_task::{
@@ -8,7 +9,6 @@ pub use crate::{
     era_common::AddressInBlock,
     utils::TaskPerfAggregator,
 };
-pub use cardano_multiplatform_lib::genesis::byron::config::GenesisData;
 pub use pallas::ledger::traverse::MultiEraBlock;
 pub use paste::paste;
 pub use shred::{DispatcherBuilder, Read, ResourceId, System, SystemData, World, Write};
@@ -16,7 +16,7 @@ pub use std::sync::{Arc, Mutex};
 
 macro_rules! era_to_block {
     (genesis) => {
-        GenesisData
+        GenesisFiles
     };
     (byron) => {
         MultiEraBlock<'a>
@@ -3,7 +3,8 @@ use std::collections::BTreeSet;
 use entity::{
     prelude::*,
     sea_orm::{
-        entity::*, prelude::*, ColumnTrait, Condition, DatabaseTransaction, QueryOrder, Set,
+        entity::*, prelude::*, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction,
+        QueryOrder, Set, Statement, Value,
     },
 };
 use std::collections::BTreeMap;
@@ -307,3 +308,171 @@ pub async fn input_from_pointer(
         .await?;
     Ok(inputs)
 }
+
+pub async fn insert_utxos(
+    outputs: &[TransactionOutputModel],
+    txn: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    if outputs.is_empty() {
+        return Ok(());
+    }
+
+    Utxo::insert_many(outputs.iter().map(|output| UtxoActiveModel {
+        utxo_id: Set(output.id),
+        address_id: Set(output.address_id),
+    }))
+    .exec(txn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_spent_utxos(
+    inputs: &[TransactionInputModel],
+    txn: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    if inputs.is_empty() {
+        return Ok(());
+    }
+
+    Utxo::delete_many()
+        .filter(UtxoColumn::UtxoId.is_in(inputs.iter().map(|input| input.utxo_id)))
+        .exec(txn)
+        .await?;
+
+    Ok(())
+}
+
+// postgres limits the number of parameters of a statement to 65535
+const UPDATE_BATCH_SIZE: usize = 10000;
+
+/// Placeholders of a `VALUES` list with one row per entry
+/// ex: `($1::bigint, $2::integer), ($3::bigint, $4::integer)`
+fn values_placeholders(row_count: usize, column_types: &[&str]) -> String {
+    (0..row_count)
+        .map(|row| {
+            let columns = column_types
+                .iter()
+                .enumerate()
+                .map(|(column, column_type)| {
+                    format!(
+                        "${}::{}",
+                        row * column_types.len() + column + 1,
+                        column_type
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("({})", columns)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub async fn update_output_amounts(
+    db_tx: &DatabaseTransaction,
+    amounts: &[(
+        i64,   /* txid */
+        usize, /* output index */
+        u64,   /* amount */
+    )],
+) -> Result<(), DbErr> {
+    // note: a single UPDATE per batch instead of one per output
+    for batch in amounts.chunks(UPDATE_BATCH_SIZE) {
+        let sql = format!(
+            r#"UPDATE "TransactionOutput" SET "amount" = "new"."amount"
+            FROM (VALUES {}) AS "new"("tx_id", "output_index", "amount")
+            WHERE "TransactionOutput"."tx_id" = "new"."tx_id"
+            AND "TransactionOutput"."output_index" = "new"."output_index""#,
+            values_placeholders(batch.len(), &["bigint", "integer", "bigint"])
+        );
+        let values = batch
+            .iter()
+            .flat_map(|(tx_id, output_index, amount)| {
+                [
+                    Value::from(*tx_id),
+                    Value::from(*output_index as i32),
+                    Value::from(*amount as i64),
+                ]
+            })
+            .collect::<Vec<_>>();
+        db_tx
+            .execute(Statement::from_sql_and_values(
+                db_tx.get_database_backend(),
+                &sql,
+                values,
+            ))
+            .await?;
+    }
+    Ok(())
+}
+
+pub async fn update_transaction_fees(
+    db_tx: &DatabaseTransaction,
+    fees: &[(i64 /* txid */, u64 /* fee */)],
+) -> Result<(), DbErr> {
+    for batch in fees.chunks(UPDATE_BATCH_SIZE) {
+        let sql = format!(
+            r#"UPDATE "Transaction" SET "fee" = "new"."fee"
+            FROM (VALUES {}) AS "new"("id", "fee")
+            WHERE "Transaction"."id" = "new"."id""#,
+            values_placeholders(batch.len(), &["bigint", "bigint"])
+        );
+        let values = batch
+            .iter()
+            .flat_map(|(tx_id, fee)| [Value::from(*tx_id), Value::from(*fee as i64)])
+            .collect::<Vec<_>>();
+        db_tx
+            .execute(Statement::from_sql_and_values(
+                db_tx.get_database_backend(),
+                &sql,
+                values,
+            ))
+            .await?;
+    }
+    Ok(())
+}
+
+pub async fn update_transaction_values(
+    db_tx: &DatabaseTransaction,
+    values: &[(
+        i64,         /* txid */
+        Option<u64>, /* fee */
+        Option<u64>, /* ttl */
+        Option<u64>, /* validity start */
+        i32,         /* size */
+    )],
+) -> Result<(), DbErr> {
+    for batch in values.chunks(UPDATE_BATCH_SIZE) {
+        let sql = format!(
+            r#"UPDATE "Transaction" SET "fee" = "new"."fee", "ttl" = "new"."ttl",
+            "validity_start" = "new"."validity_start", "size" = "new"."size"
+            FROM (VALUES {}) AS "new"("id", "fee", "ttl", "validity_start", "size")
+            WHERE "Transaction"."id" = "new"."id""#,
+            values_placeholders(
+                batch.len(),
+                &["bigint", "bigint", "bigint", "bigint", "integer"]
+            )
+        );
+        let values = batch
+            .iter()
+            .flat_map(|(tx_id, fee, ttl, validity_start, size)| {
+                [
+                    Value::from(*tx_id),
+                    Value::from(fee.map(|fee| fee as i64)),
+                    Value::from(ttl.map(|ttl| ttl as i64)),
+                    Value::from(validity_start.map(|start| start as i64)),
+                    Value::from(*size),
+                ]
+            })
+            .collect::<Vec<_>>();
+        db_tx
+            .execute(Statement::from_sql_and_values(
+                db_tx.get_database_backend(),
+                &sql,
+                values,
+            ))
+            .await?;
+    }
+    Ok(())
+}
@@ -25,9 +25,9 @@ carp_task! {
 
 async fn handle_block(
     db_tx: &DatabaseTransaction,
-    block: BlockInfo<'_, GenesisData>,
+    block: BlockInfo<'_, GenesisFiles>,
 ) -> Result<BlockModel, DbErr> {
-    let genesis_hash = block.1.genesis_prev.to_bytes();
+    let genesis_hash = block.1.byron.genesis_prev.to_bytes();
 
     let block = BlockActiveModel {
         era: Set(EraValue::Byron.into()),
@@ -37,6 +37,7 @@ async fn handle_block(
         height: Set(0),
         epoch: Set(0),
         slot: Set(0),
+        time: Set(Some(block.2.time as i64)),
         ..Default::default()
     };
 
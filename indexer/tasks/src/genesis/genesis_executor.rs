@@ -3,16 +3,16 @@ use std::sync::{Arc, Mutex};
 use crate::dsl::database_task::BlockInfo;
 use crate::dsl::database_task::TaskRegistryEntry;
 use crate::execution_plan::ExecutionPlan;
+use crate::genesis::genesis_files::GenesisFiles;
 use crate::utils::find_task_registry_entry;
 use crate::utils::TaskPerfAggregator;
-use cardano_multiplatform_lib::genesis::byron::config::GenesisData;
 use entity::sea_orm::{prelude::*, DatabaseTransaction};
 use shred::{DispatcherBuilder, World};
 use tokio::runtime::Handle;
 
 pub async fn process_genesis_block(
     txn: &DatabaseTransaction,
-    block: BlockInfo<'_, GenesisData>,
+    block: BlockInfo<'_, GenesisFiles>,
     exec_plan: &ExecutionPlan,
     perf_aggregator: Arc<Mutex<TaskPerfAggregator>>,
 ) -> Result<(), DbErr> {
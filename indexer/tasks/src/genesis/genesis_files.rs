@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use cardano_multiplatform_lib::genesis::byron::config::GenesisData;
+use serde::Deserialize;
+
+/// The genesis files of a network
+/// Only the Byron genesis is required since the Shelley and Alonzo genesis files are optional
+pub struct GenesisFiles {
+    pub byron: GenesisData,
+    pub shelley: Option<ShelleyGenesisData>,
+    pub alonzo: Option<AlonzoGenesisData>,
+}
+
+impl GenesisFiles {
+    pub fn has_balances(&self) -> bool {
+        !self.byron.avvm_distr.is_empty()
+            || !self.byron.non_avvm_balances.is_empty()
+            || self
+                .shelley
+                .as_ref()
+                .map_or(false, |shelley| !shelley.initial_funds.is_empty())
+    }
+
+    pub fn has_staking(&self) -> bool {
+        self.shelley
+            .as_ref()
+            .and_then(|shelley| shelley.staking.as_ref())
+            .map_or(false, |staking| {
+                !staking.pools.is_empty() || !staking.stake.is_empty()
+            })
+    }
+}
+
+// note: we only parse the fields we use. Everything else in the file is ignored
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShelleyGenesisData {
+    // blake2b256 of the file. Not part of the JSON
+    #[serde(skip)]
+    pub hash: [u8; 32],
+    // in seconds. Not always a whole number (ex: 0.1 for some devnets)
+    pub slot_length: f64,
+    // number of slots in an epoch
+    pub epoch_length: u64,
+    // number of genesis delegates that have to propose the same parameter update
+    pub update_quorum: u64,
+    pub protocol_params: ShelleyProtocolParams,
+    // hex-encoded address -> lovelace
+    #[serde(default)]
+    pub initial_funds: BTreeMap<String, u64>,
+    pub staking: Option<ShelleyGenesisStaking>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShelleyProtocolParams {
+    pub min_fee_a: Option<u64>,
+    pub min_fee_b: Option<u64>,
+    pub max_block_body_size: Option<u64>,
+    pub max_tx_size: Option<u64>,
+    pub max_block_header_size: Option<u64>,
+    pub key_deposit: Option<u64>,
+    pub pool_deposit: Option<u64>,
+    pub e_max: Option<u64>,
+    pub n_opt: Option<u64>,
+    pub protocol_version: Option<ShelleyProtocolVersion>,
+    pub min_pool_cost: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShelleyProtocolVersion {
+    pub major: u64,
+    pub minor: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShelleyGenesisStaking {
+    // hex-encoded pool keyhash -> pool parameters
+    #[serde(default)]
+    pub pools: BTreeMap<String, ShelleyGenesisPool>,
+    // hex-encoded stake keyhash -> hex-encoded pool keyhash
+    #[serde(default)]
+    pub stake: BTreeMap<String, String>,
+}
+
+// same format as the ToJSON instance of PoolParams in cardano-ledger
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShelleyGenesisPool {
+    // hex-encoded
+    pub vrf: String,
+    pub pledge: u64,
+    pub cost: u64,
+    // decimal number between 0 and 1
+    pub margin: f64,
+    pub reward_account: ShelleyGenesisRewardAccount,
+    // hex-encoded keyhashes
+    #[serde(default)]
+    pub owners: Vec<String>,
+    #[serde(default)]
+    pub relays: Vec<ShelleyGenesisRelay>,
+    pub metadata: Option<ShelleyGenesisPoolMetadata>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShelleyGenesisRewardAccount {
+    // Mainnet or Testnet
+    pub network: String,
+    pub credential: ShelleyGenesisCredential,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum ShelleyGenesisCredential {
+    // hex-encoded
+    #[serde(rename = "key hash", alias = "keyHash")]
+    KeyHash(String),
+    // hex-encoded
+    #[serde(rename = "script hash", alias = "scriptHash")]
+    ScriptHash(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum ShelleyGenesisRelay {
+    #[serde(rename = "single host address")]
+    SingleHostAddr {
+        #[serde(rename = "IPv4")]
+        ipv4: Option<std::net::Ipv4Addr>,
+        #[serde(rename = "IPv6")]
+        ipv6: Option<std::net::Ipv6Addr>,
+        port: Option<u32>,
+    },
+    #[serde(rename = "single host name")]
+    SingleHostName {
+        #[serde(rename = "dnsName")]
+        dns_name: String,
+        port: Option<u32>,
+    },
+    #[serde(rename = "multi host name")]
+    MultiHostName {
+        #[serde(rename = "dnsName")]
+        dns_name: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShelleyGenesisPoolMetadata {
+    pub url: String,
+    // hex-encoded
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlonzoGenesisData {
+    pub max_value_size: Option<u64>,
+    pub collateral_percentage: Option<u64>,
+    pub max_collateral_inputs: Option<u64>,
+}
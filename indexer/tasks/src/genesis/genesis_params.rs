@@ -0,0 +1,70 @@
+use super::genesis_block::GenesisBlockTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use entity::sea_orm::{prelude::*, Set};
+
+carp_task! {
+  name GenesisParamsTask;
+  configuration EmptyConfig;
+  doc "Adds the protocol parameters from the Shelley and Alonzo genesis as the parameters of epoch 0.
+       Later epochs without an update inherit them";
+  era genesis;
+  dependencies [GenesisBlockTask];
+  read [genesis_block];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.shelley.is_some()
+  };
+  execute |previous_data, task| handle_params(
+      task.db_tx,
+      task.block,
+      previous_data.genesis_block.as_ref().unwrap(),
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_params(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, GenesisFiles>,
+    database_block: &BlockModel,
+) -> Result<(), DbErr> {
+    let params = &block.1.shelley.as_ref().unwrap().protocol_params;
+    let alonzo = block.1.alonzo.as_ref();
+
+    EpochParamsActiveModel {
+        epoch: Set(0),
+        block_id: Set(database_block.id),
+        min_fee_a: Set(params.min_fee_a.map(|val| val as i64)),
+        min_fee_b: Set(params.min_fee_b.map(|val| val as i64)),
+        max_block_body_size: Set(params.max_block_body_size.map(|val| val as i32)),
+        max_tx_size: Set(params.max_tx_size.map(|val| val as i32)),
+        max_block_header_size: Set(params.max_block_header_size.map(|val| val as i32)),
+        key_deposit: Set(params.key_deposit.map(|val| val as i64)),
+        pool_deposit: Set(params.pool_deposit.map(|val| val as i64)),
+        max_epoch: Set(params.e_max.map(|val| val as i32)),
+        optimal_pool_count: Set(params.n_opt.map(|val| val as i32)),
+        protocol_major: Set(params
+            .protocol_version
+            .as_ref()
+            .map(|version| version.major as i32)),
+        protocol_minor: Set(params
+            .protocol_version
+            .as_ref()
+            .map(|version| version.minor as i32)),
+        min_pool_cost: Set(params.min_pool_cost.map(|val| val as i64)),
+        max_value_size: Set(alonzo
+            .and_then(|alonzo| alonzo.max_value_size)
+            .map(|val| val as i32)),
+        collateral_percentage: Set(alonzo
+            .and_then(|alonzo| alonzo.collateral_percentage)
+            .map(|val| val as i32)),
+        max_collateral_inputs: Set(alonzo
+            .and_then(|alonzo| alonzo.max_collateral_inputs)
+            .map(|val| val as i32)),
+    }
+    .insert(db_tx)
+    .await?;
+
+    Ok(())
+}
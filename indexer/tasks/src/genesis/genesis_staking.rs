@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use super::genesis_files::{ShelleyGenesisCredential, ShelleyGenesisPool, ShelleyGenesisRelay};
+use super::genesis_txs::GenesisTransactionTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::multiera::{
+    multiera_pool_registration::insert_stake_pools,
+    multiera_stake_credentials::insert_stake_credentials,
+};
+use entity::sea_orm::{prelude::*, Set};
+use pallas::codec::utils::Nullable;
+use pallas::crypto::hash::Hash;
+use pallas::ledger::primitives::{
+    alonzo::{Certificate, PoolMetadata, RationalNumber, Relay, StakeCredential},
+    Fragment,
+};
+
+carp_task! {
+  name GenesisStakingTask;
+  configuration EmptyConfig;
+  doc "Adds the pools (along with their registration parameters) and delegations from the staking section of the Shelley genesis.
+       Since they don't come from a real transaction, they are attached to a pseudo-transaction whose hash is the hash of the Shelley genesis file";
+  era genesis;
+  dependencies [GenesisTransactionTask];
+  read [genesis_block, genesis_txs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.has_staking()
+  };
+  execute |previous_data, task| handle_staking(
+      task.db_tx,
+      task.block,
+      previous_data.genesis_block.as_ref().unwrap(),
+      &previous_data.genesis_txs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_staking(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, GenesisFiles>,
+    database_block: &BlockModel,
+    genesis_txs: &[TransactionModel],
+) -> Result<(), DbErr> {
+    let shelley = block.1.shelley.as_ref().unwrap();
+    let staking = shelley.staking.as_ref().unwrap();
+
+    let staking_tx = TransactionActiveModel {
+        block_id: Set(database_block.id),
+        hash: Set(shelley.hash.to_vec()),
+        is_valid: Set(true),
+        payload: Set(vec![]),
+        // placed after the genesis balances
+        tx_index: Set(genesis_txs.len() as i32),
+        ..Default::default()
+    }
+    .insert(db_tx)
+    .await?;
+
+    let mut queued_pools = BTreeMap::<Vec<u8>, i64>::default();
+    for operator in staking.pools.keys().chain(staking.stake.values()) {
+        queued_pools.insert(parse_keyhash(operator)?.to_vec(), staking_tx.id);
+    }
+    let pools = insert_stake_pools(&queued_pools, db_tx).await?;
+
+    if !staking.pools.is_empty() {
+        let mut registrations = Vec::<PoolRegistrationActiveModel>::default();
+        for (operator, params) in staking.pools.iter() {
+            let operator = parse_keyhash(operator)?;
+            let vrf_keyhash = parse_hash::<32>(&params.vrf)?;
+            let margin = margin_to_rational(params.margin)?;
+            let reward_account = to_reward_account(params)?;
+            let relays = to_relays(params);
+            let pool_metadata = match &params.metadata {
+                Some(metadata) => Some(PoolMetadata {
+                    url: metadata.url.clone(),
+                    hash: parse_hash::<32>(&metadata.hash)?,
+                }),
+                None => None,
+            };
+            let pool_owners = params
+                .owners
+                .iter()
+                .map(|owner| parse_keyhash(owner))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            registrations.push(PoolRegistrationActiveModel {
+                pool_id: Set(pools[&operator.to_vec()].id),
+                tx_id: Set(staking_tx.id),
+                vrf_keyhash: Set(vrf_keyhash.to_vec()),
+                pledge: Set(params.pledge as i64),
+                cost: Set(params.cost as i64),
+                margin_numerator: Set(margin.numerator as i64),
+                margin_denominator: Set(margin.denominator as i64),
+                reward_address: Set(reward_account.clone()),
+                relays: Set(relays.encode_fragment().unwrap()),
+                metadata_url: Set(pool_metadata.as_ref().map(|metadata| metadata.url.clone())),
+                metadata_hash: Set(pool_metadata
+                    .as_ref()
+                    .map(|metadata| metadata.hash.to_vec())),
+                // the certificate that would have registered the pool on-chain
+                payload: Set(Certificate::PoolRegistration {
+                    operator,
+                    vrf_keyhash,
+                    pledge: params.pledge,
+                    cost: params.cost,
+                    margin,
+                    reward_account: reward_account.into(),
+                    pool_owners,
+                    relays,
+                    pool_metadata,
+                }
+                .encode_fragment()
+                .unwrap()),
+                ..Default::default()
+            });
+        }
+        PoolRegistration::insert_many(registrations)
+            .exec(db_tx)
+            .await?;
+    }
+
+    // (credential, pool)
+    let mut delegations = Vec::<(Vec<u8>, Vec<u8>)>::default();
+    for (stake_key, pool) in staking.stake.iter() {
+        let credential = StakeCredential::AddrKeyhash(parse_keyhash(stake_key)?)
+            .encode_fragment()
+            .unwrap();
+        delegations.push((credential, parse_keyhash(pool)?.to_vec()));
+    }
+
+    let queued_creds = delegations
+        .iter()
+        .map(|(credential, _)| (credential.clone(), staking_tx.id))
+        .collect::<BTreeMap<_, _>>();
+    let credentials = insert_stake_credentials(&queued_creds, db_tx).await?;
+
+    if !delegations.is_empty() {
+        StakeDelegation::insert_many(delegations.iter().map(|(credential, pool)| {
+            StakeDelegationActiveModel {
+                credential_id: Set(credentials[credential].id),
+                pool_credential: Set(pool.clone()),
+                tx_id: Set(staking_tx.id),
+                epoch: Set(0),
+                ..Default::default()
+            }
+        }))
+        .exec(db_tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+fn parse_hash<const BYTES: usize>(hash: &str) -> Result<Hash<BYTES>, DbErr> {
+    hash.parse::<Hash<BYTES>>()
+        .map_err(|_| DbErr::Custom(format!("invalid hash {} in genesis staking", hash)))
+}
+
+fn parse_keyhash(keyhash: &str) -> Result<Hash<28>, DbErr> {
+    parse_hash::<28>(keyhash)
+}
+
+fn to_reward_account(params: &ShelleyGenesisPool) -> Result<Vec<u8>, DbErr> {
+    // header: address type (stake key / stake script) & network id
+    let (header, credential) = match &params.reward_account.credential {
+        ShelleyGenesisCredential::KeyHash(hash) => (0b1110_0000, hash),
+        ShelleyGenesisCredential::ScriptHash(hash) => (0b1111_0000, hash),
+    };
+    let network_id = match params.reward_account.network.as_str() {
+        "Mainnet" => 1,
+        _ => 0,
+    };
+    let mut reward_account = vec![header | network_id];
+    reward_account.extend(parse_hash::<28>(credential)?.to_vec());
+    Ok(reward_account)
+}
+
+fn to_relays(params: &ShelleyGenesisPool) -> Vec<Relay> {
+    params
+        .relays
+        .iter()
+        .map(|relay| match relay {
+            ShelleyGenesisRelay::SingleHostAddr { ipv4, ipv6, port } => Relay::SingleHostAddr(
+                port.map_or(Nullable::Null, |port| Nullable::Some(port.into())),
+                ipv4.map_or(Nullable::Null, |ip| {
+                    Nullable::Some(ip.octets().to_vec().into())
+                }),
+                // the ledger serializes IPv6 addresses as 4 little-endian 32-bit words
+                ipv6.map_or(Nullable::Null, |ip| {
+                    let bytes = ip
+                        .octets()
+                        .chunks(4)
+                        .flat_map(|word| word.iter().rev().copied())
+                        .collect::<Vec<u8>>();
+                    Nullable::Some(bytes.into())
+                }),
+            ),
+            ShelleyGenesisRelay::SingleHostName { dns_name, port } => Relay::SingleHostName(
+                port.map_or(Nullable::Null, |port| Nullable::Some(port.into())),
+                dns_name.clone(),
+            ),
+            ShelleyGenesisRelay::MultiHostName { dns_name } => {
+                Relay::MultiHostName(dns_name.clone())
+            }
+        })
+        .collect()
+}
+
+// the genesis stores the margin as a decimal number but the ledger stores it as a fraction
+fn margin_to_rational(margin: f64) -> Result<RationalNumber, DbErr> {
+    let invalid = || DbErr::Custom(format!("invalid margin {} in genesis staking", margin));
+    if !(0.0..=1.0).contains(&margin) {
+        return Err(invalid());
+    }
+    // note: Display never uses the scientific notation for f64
+    let decimal = margin.to_string();
+    let (integer, fraction) = decimal.split_once('.').unwrap_or((&decimal, ""));
+    let denominator = 10u64
+        .checked_pow(fraction.len() as u32)
+        .ok_or_else(invalid)?;
+    let numerator = format!("{}{}", integer, fraction)
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+
+    let gcd = {
+        let (mut a, mut b) = (numerator, denominator);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    Ok(RationalNumber {
+        numerator: numerator / gcd,
+        denominator: denominator / gcd,
+    })
+}
@@ -2,9 +2,7 @@ extern crate shred;
 
 use crate::config::EmptyConfig::EmptyConfig;
 use cardano_multiplatform_lib::{
-    byron::ByronAddress,
-    genesis::byron::{config::GenesisData, parse::redeem_pubkey_to_txid},
-    ledger::common::value::Value,
+    byron::ByronAddress, genesis::byron::parse::redeem_pubkey_to_txid, ledger::common::value::Value,
 };
 use entity::{
     prelude::*,
@@ -17,19 +15,20 @@ use crate::dsl::task_macro::*;
 use crate::utils::{blake2b256, TaskPerfAggregator};
 use entity::sea_orm::Iterable;
 use futures::future::try_join;
+use pallas::ledger::primitives::{alonzo, Fragment};
 
 use super::genesis_block::GenesisBlockTask;
 
 carp_task! {
   name GenesisTransactionTask;
   configuration EmptyConfig;
-  doc "Parses Genesis transactions (avvm & non-avvm balances from genesis)";
+  doc "Parses Genesis transactions (avvm & non-avvm balances from the Byron genesis and initialFunds from the Shelley genesis)";
   era genesis;
   dependencies [GenesisBlockTask];
   read [genesis_block];
   write [genesis_txs, genesis_addresses, genesis_outputs];
   should_add_task |block, _properties| {
-    !block.1.avvm_distr.is_empty() || !block.1.non_avvm_balances.is_empty()
+    block.1.has_balances()
   };
   execute |previous_data, task| handle_txs(
       task.db_tx,
@@ -45,7 +44,7 @@ carp_task! {
 
 async fn handle_txs(
     db_tx: &DatabaseTransaction,
-    block: BlockInfo<'_, GenesisData>,
+    block: BlockInfo<'_, GenesisFiles>,
     database_block: &BlockModel,
 ) -> Result<
     (
@@ -62,10 +61,12 @@ async fn handle_txs(
     // note: genesis file is a JSON structure, so there shouldn't be duplicate addresses
     // even across avvm and non-avvm it should be unique, otherwise two txs with the same tx hash would exist
     let mut addresses: Vec<Box<dyn Fn(i64) -> AddressActiveModel>> = vec![];
-    let mut outputs: Vec<cardano_multiplatform_lib::byron::ByronTxout> = vec![];
+    // (payload, amount) of the single output of each tx
+    let mut outputs: Vec<(Vec<u8>, u64)> = vec![];
 
-    for (pub_key, amount) in block.1.avvm_distr.iter() {
-        let (tx_hash, byron_addr) = redeem_pubkey_to_txid(pub_key, Some(block.1.protocol_magic));
+    for (pub_key, amount) in block.1.byron.avvm_distr.iter() {
+        let (tx_hash, byron_addr) =
+            redeem_pubkey_to_txid(pub_key, Some(block.1.byron.protocol_magic));
 
         // note: strictly speaking, genesis txs are unordered so there is no defined index
         let tx_index = transactions.len() as i32;
@@ -85,14 +86,12 @@ async fn handle_txs(
             ..Default::default()
         }));
 
-        outputs.push(cardano_multiplatform_lib::byron::ByronTxout::new(
-            &byron_addr,
-            amount,
-        ));
+        let output = cardano_multiplatform_lib::byron::ByronTxout::new(&byron_addr, amount);
+        outputs.push((output.to_bytes(), u64::from(output.amount())));
     }
 
     // note: empty on mainnet
-    for (byron_addr, amount) in block.1.non_avvm_balances.iter() {
+    for (byron_addr, amount) in block.1.byron.non_avvm_balances.iter() {
         let tx_hash = blake2b256(&byron_addr.to_bytes());
 
         // println!("{}", amount.to_str());
@@ -117,9 +116,42 @@ async fn handle_txs(
             ..Default::default()
         }));
 
-        outputs.push(cardano_multiplatform_lib::byron::ByronTxout::new(
-            byron_addr, amount,
-        ));
+        let output = cardano_multiplatform_lib::byron::ByronTxout::new(byron_addr, amount);
+        outputs.push((output.to_bytes(), u64::from(output.amount())));
+    }
+
+    // note: empty on mainnet & preprod. Used by networks that start directly in Shelley
+    // https://github.com/input-output-hk/cardano-ledger/blob/ac51494e151af0ad99b937a787458ce71db0aaea/eras/shelley/impl/src/Cardano/Ledger/Shelley/Genesis.hs#L433
+    if let Some(shelley) = &block.1.shelley {
+        for (address, amount) in shelley.initial_funds.iter() {
+            let address = hex::decode(address)
+                .map_err(|_| DbErr::Custom(format!("invalid initialFunds address {}", address)))?;
+            let tx_hash = blake2b256(&address);
+
+            // note: strictly speaking, genesis txs are unordered so there is no defined index
+            let tx_index = transactions.len() as i32;
+            transactions.push(TransactionActiveModel {
+                block_id: Set(database_block.id),
+                hash: Set(tx_hash.to_vec()),
+                is_valid: Set(true),
+                payload: Set(address.clone()),
+                tx_index: Set(tx_index),
+                ..Default::default()
+            });
+
+            let output = alonzo::TransactionOutput {
+                address: address.clone().into(),
+                amount: alonzo::Value::Coin(*amount),
+                datum_hash: None,
+            };
+            addresses.push(Box::new(move |tx_id| AddressActiveModel {
+                payload: Set(address.clone()),
+                first_tx: Set(tx_id),
+                ..Default::default()
+            }));
+
+            outputs.push((output.encode_fragment().unwrap(), *amount));
+        }
     }
 
     let inserted_txs = bulk_insert_txs(db_tx, &transactions).await?;
@@ -139,10 +171,11 @@ async fn handle_txs(
         .map(|(i, (tx, addr))| TransactionOutputActiveModel {
             address_id: Set(addr.id),
             tx_id: Set(tx.id),
-            payload: Set(outputs[i].to_bytes()),
+            payload: Set(outputs[i].0.clone()),
             // recall: genesis txs are hashes of addresses
             // so all txs have a single output
             output_index: Set(0),
+            amount: Set(Some(outputs[i].1 as i64)),
             ..Default::default()
         });
     let inserted_outputs = TransactionOutput::insert_many(outputs_to_add)
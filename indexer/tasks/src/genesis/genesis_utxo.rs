@@ -0,0 +1,23 @@
+use super::genesis_txs::GenesisTransactionTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::era_common::insert_utxos;
+
+carp_task! {
+  name GenesisUtxoTask;
+  configuration EmptyConfig;
+  doc "Adds the genesis outputs to the live UTXO set";
+  era genesis;
+  dependencies [GenesisTransactionTask];
+  read [genesis_outputs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.has_balances()
+  };
+  execute |previous_data, task| insert_utxos(
+      &previous_data.genesis_outputs,
+      task.db_tx,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
@@ -1,3 +1,7 @@
 pub mod genesis_block;
 pub mod genesis_executor;
+pub mod genesis_files;
+pub mod genesis_params;
+pub mod genesis_staking;
 pub mod genesis_txs;
+pub mod genesis_utxo;
@@ -1,17 +1,33 @@
 pub mod multiera_address;
 pub mod multiera_address_credential_relations;
 pub mod multiera_asset_mint;
+pub mod multiera_asset_utxo;
 pub mod multiera_block;
+pub mod multiera_block_backfill;
 pub mod multiera_cip25entry;
+pub mod multiera_cip36_registration;
+pub mod multiera_cip68_entry;
 pub mod multiera_datum;
 pub mod multiera_executor;
 pub mod multiera_metadata;
+pub mod multiera_metadata_json;
+pub mod multiera_mir;
+pub mod multiera_output_datum;
+pub mod multiera_output_script;
+pub mod multiera_param_update;
+pub mod multiera_pool_registration;
+pub mod multiera_redeemer;
 pub mod multiera_reference_inputs;
+pub mod multiera_script;
 pub mod multiera_stake_credentials;
+pub mod multiera_stake_delegation;
 pub mod multiera_tx_credential_relations;
 pub mod multiera_txs;
 pub mod multiera_unused_input;
 pub mod multiera_used_inputs;
 pub mod multiera_used_outputs;
+pub mod multiera_utxo;
+pub mod multiera_value_backfill;
+pub mod multiera_withdrawal;
 pub mod relation_map;
 pub mod utils;
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::ledger::traverse::{Asset, MultiEraBlock, MultiEraOutput};
+
+use super::{
+    multiera_asset_mint::MultieraAssetMintTask, multiera_used_outputs::MultieraOutputTask,
+};
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraAssetUtxoTask;
+  configuration EmptyConfig;
+  doc "Adds the native assets held by each output to the database.
+       Note: this relies on the assets added by MultieraAssetMintTask so both have to be enabled since genesis";
+  era multiera;
+  dependencies [MultieraOutputTask, MultieraAssetMintTask];
+  read [multiera_txs, multiera_outputs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| {
+        tx.outputs().iter().any(|output| !output.non_ada_assets().is_empty())
+            || tx.collateral_return().iter().any(|output| !output.non_ada_assets().is_empty())
+    })
+  };
+  execute |previous_data, task| handle_asset_utxos(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_outputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+struct QueuedAssetUtxo {
+    tx_id: i64,
+    idx: usize,
+    policy_id: Vec<u8>,
+    asset_name: Vec<u8>,
+    amount: u64,
+}
+
+async fn handle_asset_utxos(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_outputs: &[TransactionOutputModel],
+) -> Result<(), DbErr> {
+    let mut queued_asset_utxos = Vec::<QueuedAssetUtxo>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let outputs = tx_body.outputs();
+        if cardano_transaction.is_valid {
+            for (idx, output) in outputs.iter().enumerate() {
+                queue_assets(&mut queued_asset_utxos, cardano_transaction.id, output, idx);
+            }
+        }
+        if !cardano_transaction.is_valid {
+            if let Some(output) = tx_body.collateral_return().as_ref() {
+                queue_assets(
+                    &mut queued_asset_utxos,
+                    cardano_transaction.id,
+                    output,
+                    // only one collateral output is allowed
+                    // and its index is output.len()
+                    outputs.len(),
+                );
+            }
+        }
+    }
+
+    if queued_asset_utxos.is_empty() {
+        return Ok(());
+    }
+
+    // 1) Get the outputs these assets belong to
+    let output_id_mapping = multiera_outputs
+        .iter()
+        .map(|output| ((output.tx_id, output.output_index as usize), output.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // 2) Get the assets from the ones MultieraAssetMintTask added
+    let unique_pairs = queued_asset_utxos
+        .iter()
+        .map(|entry| (&entry.policy_id, &entry.asset_name))
+        .collect::<BTreeSet<_>>();
+
+    // https://github.com/dcSpark/carp/issues/46
+    let mut asset_conditions = Condition::any();
+    for (policy_id, asset_name) in unique_pairs.iter() {
+        asset_conditions = asset_conditions.add(
+            Condition::all()
+                .add(NativeAssetColumn::PolicyId.eq((*policy_id).clone()))
+                .add(NativeAssetColumn::AssetName.eq((*asset_name).clone())),
+        );
+    }
+    let found_assets = NativeAsset::find()
+        .filter(asset_conditions)
+        .all(db_tx)
+        .await?;
+    let asset_id_mapping = found_assets
+        .iter()
+        .map(|asset| ((&asset.policy_id, &asset.asset_name), asset.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // 3) Add the balances
+    let mut to_add = Vec::with_capacity(queued_asset_utxos.len());
+    for entry in queued_asset_utxos.iter() {
+        let asset_id = match asset_id_mapping.get(&(&entry.policy_id, &entry.asset_name)) {
+            Some(asset_id) => *asset_id,
+            None => panic!(
+                "Asset not found in database: {}.{}",
+                hex::encode(&entry.policy_id),
+                hex::encode(&entry.asset_name)
+            ),
+        };
+        to_add.push(to_asset_utxo(
+            output_id_mapping[&(entry.tx_id, entry.idx)],
+            asset_id,
+            entry.amount,
+        ));
+    }
+    AssetUtxo::insert_many(to_add).exec(db_tx).await?;
+
+    Ok(())
+}
+
+fn to_asset_utxo(utxo_id: i64, asset_id: i64, amount: u64) -> AssetUtxoActiveModel {
+    AssetUtxoActiveModel {
+        utxo_id: Set(utxo_id),
+        asset_id: Set(asset_id),
+        amount: Set(Decimal::from(amount)),
+    }
+}
+
+fn queue_assets(
+    queued_asset_utxos: &mut Vec<QueuedAssetUtxo>,
+    tx_id: i64,
+    output: &MultiEraOutput,
+    idx: usize,
+) {
+    for asset in output.non_ada_assets() {
+        if let Asset::NativeAsset(policy_id, asset_name, amount) = asset {
+            queued_asset_utxos.push(QueuedAssetUtxo {
+                tx_id,
+                idx,
+                policy_id: policy_id.to_vec(),
+                asset_name,
+                amount,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_amounts_above_i64_max() {
+        let model = to_asset_utxo(1, 2, u64::MAX);
+        assert_eq!(model.amount.unwrap().to_string(), "18446744073709551615");
+    }
+}
@@ -3,6 +3,7 @@ use crate::dsl::task_macro::*;
 use crate::era_common::block_from_hash;
 use crate::utils::blake2b256;
 use entity::sea_orm::{DatabaseTransaction, Set};
+use pallas::crypto::hash::Hasher;
 use pallas::ledger::primitives::alonzo::{self};
 use pallas::ledger::primitives::Fragment;
 use pallas::ledger::traverse::MultiEraBlock;
@@ -37,13 +38,55 @@ async fn handle_block(
     if readonly {
         return block_from_hash(db_tx, &hash).await;
     }
+    let header_values = get_header_values(block.1);
     let block = BlockActiveModel {
         era: Set(block.2.era.into()),
         hash: Set(hash.to_vec()),
         height: Set(block.1.number() as i32),
         epoch: Set(block.2.epoch.unwrap() as i32),
         slot: Set(block.1.slot() as i32),
+        pool_id: Set(Some(header_values.pool_id)),
+        vrf_output: Set(Some(header_values.vrf_output)),
+        protocol_major: Set(Some(header_values.protocol_major as i32)),
+        protocol_minor: Set(Some(header_values.protocol_minor as i32)),
+        body_size: Set(Some(header_values.body_size as i32)),
+        tx_count: Set(Some(block.1.txs().len() as i32)),
+        time: Set(Some(block.2.time as i64)),
         ..Default::default()
     };
     block.insert(db_tx).await
 }
+
+pub struct HeaderValues {
+    pub pool_id: Vec<u8>,
+    pub vrf_output: Vec<u8>,
+    pub protocol_major: u64,
+    pub protocol_minor: u64,
+    pub body_size: u64,
+}
+
+pub fn get_header_values(block: &MultiEraBlock) -> HeaderValues {
+    match block {
+        MultiEraBlock::AlonzoCompatible(x, _) => {
+            let header = &x.header.header_body;
+            HeaderValues {
+                pool_id: Hasher::<224>::hash(&header.issuer_vkey).to_vec(),
+                vrf_output: header.leader_vrf.0.to_vec(),
+                protocol_major: header.protocol_major,
+                protocol_minor: header.protocol_minor,
+                body_size: header.block_body_size,
+            }
+        }
+        MultiEraBlock::Babbage(x) => {
+            let header = &x.header.header_body;
+            HeaderValues {
+                pool_id: Hasher::<224>::hash(&header.issuer_vkey).to_vec(),
+                vrf_output: header.vrf_result.0.to_vec(),
+                protocol_major: header.protocol_version.0,
+                protocol_minor: header.protocol_version.1,
+                body_size: header.block_body_size,
+            }
+        }
+        _ => panic!("get_header_values - Unhandled block type"),
+    }
+}
@@ -0,0 +1,62 @@
+use super::multiera_block::{get_header_values, MultieraBlockTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use entity::sea_orm::sea_query::Expr;
+use pallas::ledger::traverse::MultiEraBlock;
+
+carp_task! {
+  name MultieraBlockBackfillTask;
+  configuration EmptyConfig;
+  doc "Fills in the pool id, VRF output, protocol version, body size, transaction count and time of blocks that were added before these columns existed.
+       Only meant to be used with MultieraBlockTask in readonly mode (see the block_backfill execution plan)";
+  era multiera;
+  dependencies [MultieraBlockTask];
+  read [multiera_block];
+  write [];
+  should_add_task |_block, _properties| {
+    true
+  };
+  execute |previous_data, task| handle_backfill(
+      task.db_tx,
+      task.block,
+      previous_data.multiera_block.as_ref().unwrap(),
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_backfill(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    database_block: &BlockModel,
+) -> Result<(), DbErr> {
+    let header_values = get_header_values(block.1);
+    Block::update_many()
+        .col_expr(BlockColumn::PoolId, Expr::value(header_values.pool_id))
+        .col_expr(
+            BlockColumn::VrfOutput,
+            Expr::value(header_values.vrf_output),
+        )
+        .col_expr(
+            BlockColumn::ProtocolMajor,
+            Expr::value(header_values.protocol_major as i32),
+        )
+        .col_expr(
+            BlockColumn::ProtocolMinor,
+            Expr::value(header_values.protocol_minor as i32),
+        )
+        .col_expr(
+            BlockColumn::BodySize,
+            Expr::value(header_values.body_size as i32),
+        )
+        .col_expr(
+            BlockColumn::TxCount,
+            Expr::value(block.1.txs().len() as i32),
+        )
+        .col_expr(BlockColumn::Time, Expr::value(block.2.time as i64))
+        .filter(BlockColumn::Id.eq(database_block.id))
+        .exec(db_tx)
+        .await?;
+
+    Ok(())
+}
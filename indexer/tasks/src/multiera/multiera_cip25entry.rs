@@ -1,22 +1,16 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 
 use crate::config::EmptyConfig::EmptyConfig;
-use cardano_multiplatform_lib::crypto::ScriptHash;
 use entity::{
     prelude::*,
     sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
 };
+use pallas::ledger::primitives::alonzo::Metadatum;
 use pallas::ledger::primitives::Fragment;
-use pallas::{
-    codec::utils::KeyValuePairs,
-    ledger::primitives::alonzo::{self, AuxiliaryData, Metadatum, MetadatumLabel},
-};
 
 use super::{
-    multiera_asset_mint::MultieraAssetMintTask,
-    multiera_metadata::MultieraMetadataTask,
-    multiera_txs::MultieraTransactionTask,
-    utils::{cip25_parse::get_cip25_pairs, user_asset::AssetName},
+    multiera_asset_mint::MultieraAssetMintTask, multiera_metadata::MultieraMetadataTask,
+    multiera_txs::MultieraTransactionTask, utils::cip25_parse::get_cip25_pairs,
 };
 
 use crate::dsl::task_macro::*;
@@ -24,7 +18,8 @@ use crate::dsl::task_macro::*;
 carp_task! {
   name MultieraCip25EntryTask;
   configuration EmptyConfig;
-  doc "Maps CIP25 entries to the corresponding DB entry for the asset";
+  doc "Maps CIP25 entries to the corresponding DB entry for the asset along with their standard fields.
+       Entries that can't be parsed or matched to a mint are recorded in Cip25ParseError";
   era multiera;
   dependencies [MultieraMetadataTask, MultieraAssetMintTask];
   read [multiera_assets, multiera_metadata];
@@ -41,6 +36,8 @@ carp_task! {
   };
 }
 
+const CIP25_LABEL: u64 = 721;
+
 async fn handle_entries(
     db_tx: &DatabaseTransaction,
     multiera_metadata: &[TransactionMetadataModel],
@@ -61,27 +58,63 @@ async fn handle_entries(
     }
 
     let mut to_insert: Vec<Cip25EntryActiveModel> = vec![];
+    let mut errors_to_insert: Vec<Cip25ParseErrorActiveModel> = vec![];
     for metadata in multiera_metadata {
-        if let Ok(pairs) =
-            &get_cip25_pairs(&Metadatum::decode_fragment(metadata.payload.as_slice()).unwrap())
+        if metadata.label != CIP25_LABEL.to_le_bytes().to_vec() {
+            continue;
+        }
+        let pairs = match get_cip25_pairs(
+            &Metadatum::decode_fragment(metadata.payload.as_slice()).unwrap(),
+        ) {
+            Ok(pairs) => pairs,
+            Err(e) => {
+                errors_to_insert.push(Cip25ParseErrorActiveModel {
+                    metadata_id: Set(metadata.id),
+                    policy_id: Set(None),
+                    asset_name: Set(None),
+                    reason: Set(e.to_string()),
+                    ..Default::default()
+                });
+                continue;
+            }
+        };
+        for rejection in pairs.rejections.iter() {
+            errors_to_insert.push(Cip25ParseErrorActiveModel {
+                metadata_id: Set(metadata.id),
+                policy_id: Set(rejection.policy_id.clone()),
+                asset_name: Set(rejection.asset_name.clone()),
+                reason: Set(rejection.reason.to_string()),
+                ..Default::default()
+            });
+        }
+        for ((asset_name, asset), policy_id) in pairs
+            .assets
+            .iter()
+            .flat_map(|(policy_id, assets)| assets.iter().zip(std::iter::repeat(policy_id)))
         {
-            for ((asset_name, payload), policy_id) in pairs
-                .1
-                .iter()
-                .flat_map(|(policy_id, assets)| assets.iter().zip(std::iter::repeat(policy_id)))
+            match pair_id_mapping
+                .get(&policy_id)
+                .and_then(|assets| assets.get(asset_name))
             {
-                if let Some(asset_id) = pair_id_mapping
-                    .get(&policy_id)
-                    .and_then(|assets| assets.get(asset_name))
-                {
-                    to_insert.push(Cip25EntryActiveModel {
-                        metadata_id: Set(metadata.id),
-                        asset_id: Set(*asset_id),
-                        payload: Set(payload.clone()),
-                        version: Set(pairs.0.clone()),
-                        ..Default::default()
-                    });
-                }
+                Some(asset_id) => to_insert.push(Cip25EntryActiveModel {
+                    metadata_id: Set(metadata.id),
+                    asset_id: Set(*asset_id),
+                    payload: Set(asset.payload.clone()),
+                    version: Set(pairs.version.clone()),
+                    name: Set(asset.fields.name.clone()),
+                    image: Set(asset.fields.image.clone()),
+                    media_type: Set(asset.fields.media_type.clone()),
+                    description: Set(asset.fields.description.clone()),
+                    files: Set(asset.fields.files.clone()),
+                    ..Default::default()
+                }),
+                None => errors_to_insert.push(Cip25ParseErrorActiveModel {
+                    metadata_id: Set(metadata.id),
+                    policy_id: Set(Some(policy_id.clone())),
+                    asset_name: Set(Some(asset_name.clone())),
+                    reason: Set("no mint for this asset in the block".to_string()),
+                    ..Default::default()
+                }),
             }
         }
     }
@@ -89,5 +122,10 @@ async fn handle_entries(
     if !to_insert.is_empty() {
         Cip25Entry::insert_many(to_insert).exec(db_tx).await?;
     }
+    if !errors_to_insert.is_empty() {
+        Cip25ParseError::insert_many(errors_to_insert)
+            .exec(db_tx)
+            .await?;
+    }
     Ok(())
 }
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::crypto::hash::Hasher;
+use pallas::ledger::primitives::{
+    alonzo::{Metadatum, StakeCredential},
+    Fragment,
+};
+
+use super::{
+    multiera_metadata::MultieraMetadataTask,
+    utils::cip36::{parse_registration, verify_signature, REGISTRATION_LABEL, SIGNATURE_LABEL},
+};
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraCip36RegistrationTask;
+  configuration EmptyConfig;
+  doc "Adds CIP-36 (and CIP-15) vote registrations with a valid signature along with their delegations to voting keys.
+       The Cip36LatestRegistration view contains only the latest registration for each stake credential";
+  era multiera;
+  dependencies [MultieraMetadataTask];
+  read [multiera_txs, multiera_metadata];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.has_aux_data()
+  };
+  execute |previous_data, task| handle_registrations(
+      task.db_tx,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_metadata,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_registrations(
+    db_tx: &DatabaseTransaction,
+    multiera_txs: &[TransactionModel],
+    multiera_metadata: &[TransactionMetadataModel],
+) -> Result<(), DbErr> {
+    // 1) Find the registration & signature pair of each tx
+    let mut tx_metadata = BTreeMap::<i64, (Option<&Vec<u8>>, Option<&Vec<u8>>)>::default();
+    for metadata in multiera_metadata {
+        let entry = tx_metadata.entry(metadata.tx_id).or_default();
+        if metadata.label == REGISTRATION_LABEL.to_le_bytes().to_vec() {
+            entry.0 = Some(&metadata.payload);
+        } else if metadata.label == SIGNATURE_LABEL.to_le_bytes().to_vec() {
+            entry.1 = Some(&metadata.payload);
+        }
+    }
+
+    // 2) Only keep the well-formed registrations with a valid signature
+    let mut queued_registrations = Vec::new();
+    for cardano_transaction in multiera_txs {
+        // registrations in a failed transaction never take effect
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        let (registration_payload, signature_payload) =
+            match tx_metadata.get(&cardano_transaction.id) {
+                Some((Some(registration), Some(signature))) => (registration, signature),
+                _ => continue,
+            };
+        let registration = match Metadatum::decode_fragment(registration_payload)
+            .ok()
+            .as_ref()
+            .and_then(parse_registration)
+        {
+            Some(registration) => registration,
+            None => continue,
+        };
+        let signature = match Metadatum::decode_fragment(signature_payload) {
+            Ok(signature) => signature,
+            Err(_) => continue,
+        };
+        if !verify_signature(&registration, registration_payload, &signature) {
+            continue;
+        }
+        queued_registrations.push((cardano_transaction.id, registration));
+    }
+
+    if queued_registrations.is_empty() {
+        return Ok(());
+    }
+
+    // 3) Add the registrations
+    let registrations =
+        Cip36Registration::insert_many(queued_registrations.iter().map(|(tx_id, registration)| {
+            let credential =
+                StakeCredential::AddrKeyhash(Hasher::<224>::hash(&registration.stake_key));
+            Cip36RegistrationActiveModel {
+                tx_id: Set(*tx_id),
+                stake_credential: Set(credential.encode_fragment().unwrap()),
+                stake_key: Set(registration.stake_key.to_vec()),
+                reward_address: Set(registration.reward_address.clone()),
+                nonce: Set(registration.nonce),
+                voting_purpose: Set(registration.voting_purpose),
+                ..Default::default()
+            }
+        }))
+        .exec_many_with_returning(db_tx)
+        .await?;
+
+    // 4) Add the delegations of each registration
+    Cip36Delegation::insert_many(
+        registrations
+            .iter()
+            .zip(queued_registrations.iter())
+            .flat_map(|(model, (_, registration))| {
+                registration.delegations.iter().map(|(voting_key, weight)| {
+                    Cip36DelegationActiveModel {
+                        registration_id: Set(model.id),
+                        voting_key: Set(voting_key.clone()),
+                        weight: Set(*weight),
+                        ..Default::default()
+                    }
+                })
+            }),
+    )
+    .exec(db_tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::ledger::primitives::babbage::{DatumHash, DatumOption};
+use pallas::ledger::traverse::{Asset, ComputeHash, MultiEraBlock, MultiEraOutput};
+
+use super::{
+    multiera_asset_mint::MultieraAssetMintTask,
+    multiera_datum::MultieraDatumTask,
+    multiera_used_outputs::MultieraOutputTask,
+    utils::cip68::{is_reference_token, reference_name_for, user_names_for, user_token_label},
+};
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraCip68EntryTask;
+  configuration EmptyConfig;
+  doc "Pairs CIP-68 user tokens (labels 222, 333 and 444) with their reference token (label 100)
+       and records every inline datum attached to an output holding a reference token.
+       The latest metadata for an asset is the Cip68Entry with the highest id for its reference token";
+  era multiera;
+  dependencies [MultieraOutputTask, MultieraAssetMintTask, MultieraDatumTask];
+  read [multiera_txs, multiera_outputs, multiera_assets];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| {
+        tx.mint().len() > 0
+            || tx.outputs().iter().any(|output| output.datum().is_some())
+            || tx.collateral_return().iter().any(|output| output.datum().is_some())
+    })
+  };
+  execute |previous_data, task| handle_cip68(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_outputs,
+      &previous_data.multiera_assets,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+// (policy_id, asset_name)
+type AssetKey = (Vec<u8>, Vec<u8>);
+
+struct QueuedEntry {
+    tx_id: i64,
+    idx: usize,
+    policy_id: Vec<u8>,
+    asset_name: Vec<u8>,
+    datum_hash: DatumHash,
+}
+
+async fn handle_cip68(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_outputs: &[TransactionOutputModel],
+    multiera_assets: &[NativeAssetModel],
+) -> Result<(), DbErr> {
+    handle_cip68_tokens(db_tx, multiera_assets).await?;
+    handle_cip68_entries(db_tx, block, multiera_txs, multiera_outputs).await?;
+    Ok(())
+}
+
+async fn handle_cip68_tokens(
+    db_tx: &DatabaseTransaction,
+    multiera_assets: &[NativeAssetModel],
+) -> Result<(), DbErr> {
+    // 1) Find the pairs involving a token minted in this block
+    // note: either token of the pair can be minted first
+    // so a pair is added by whichever block mints the second one
+    let mut candidates = BTreeSet::<(AssetKey, AssetKey, i32)>::default();
+    for asset in multiera_assets.iter() {
+        if let Some(label) = user_token_label(&asset.asset_name) {
+            candidates.insert((
+                (asset.policy_id.clone(), asset.asset_name.clone()),
+                (
+                    asset.policy_id.clone(),
+                    reference_name_for(&asset.asset_name),
+                ),
+                label,
+            ));
+        } else if is_reference_token(&asset.asset_name) {
+            for (label, user_name) in user_names_for(&asset.asset_name) {
+                candidates.insert((
+                    (asset.policy_id.clone(), user_name),
+                    (asset.policy_id.clone(), asset.asset_name.clone()),
+                    label,
+                ));
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    // 2) Find which of these tokens exist
+    // note: the other token of the pair may not have been minted yet, in which case the pair is skipped
+    let unique_assets = candidates
+        .iter()
+        .flat_map(|(user, reference, _)| [user, reference])
+        .collect::<BTreeSet<_>>();
+
+    // https://github.com/dcSpark/carp/issues/46
+    let mut asset_conditions = Condition::any();
+    for (policy_id, asset_name) in unique_assets.iter() {
+        asset_conditions = asset_conditions.add(
+            Condition::all()
+                .add(NativeAssetColumn::PolicyId.eq(policy_id.clone()))
+                .add(NativeAssetColumn::AssetName.eq(asset_name.clone())),
+        );
+    }
+    let asset_id_mapping = NativeAsset::find()
+        .filter(asset_conditions)
+        .all(db_tx)
+        .await?
+        .drain(..)
+        .map(|asset| ((asset.policy_id, asset.asset_name), asset.id))
+        .collect::<BTreeMap<_, _>>();
+
+    let pairs = candidates
+        .iter()
+        .filter_map(|(user, reference, label)| {
+            Some((
+                *asset_id_mapping.get(user)?,
+                (*asset_id_mapping.get(reference)?, *label),
+            ))
+        })
+        .collect::<BTreeMap<_, _>>();
+    if pairs.is_empty() {
+        return Ok(());
+    }
+
+    // 3) Skip the user tokens that were already paired in a previous block
+    let already_paired = Cip68Token::find()
+        .filter(Condition::any().add(Cip68TokenColumn::UserAssetId.is_in(pairs.keys().copied())))
+        .all(db_tx)
+        .await?
+        .drain(..)
+        .map(|token| token.user_asset_id)
+        .collect::<BTreeSet<_>>();
+
+    // 4) Add the new pairs
+    let to_add = pairs
+        .iter()
+        .filter(|(user_asset_id, _)| !already_paired.contains(user_asset_id))
+        .map(
+            |(user_asset_id, (reference_asset_id, label))| Cip68TokenActiveModel {
+                user_asset_id: Set(*user_asset_id),
+                reference_asset_id: Set(*reference_asset_id),
+                label: Set(*label),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    if !to_add.is_empty() {
+        Cip68Token::insert_many(to_add).exec(db_tx).await?;
+    }
+
+    Ok(())
+}
+
+async fn handle_cip68_entries(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_outputs: &[TransactionOutputModel],
+) -> Result<(), DbErr> {
+    let mut queued_entries = Vec::<QueuedEntry>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let outputs = tx_body.outputs();
+        if cardano_transaction.is_valid {
+            for (idx, output) in outputs.iter().enumerate() {
+                queue_entries(&mut queued_entries, cardano_transaction.id, output, idx);
+            }
+        }
+        if !cardano_transaction.is_valid {
+            if let Some(output) = tx_body.collateral_return().as_ref() {
+                queue_entries(
+                    &mut queued_entries,
+                    cardano_transaction.id,
+                    output,
+                    // only one collateral output is allowed
+                    // and its index is output.len()
+                    outputs.len(),
+                );
+            }
+        }
+    }
+
+    if queued_entries.is_empty() {
+        return Ok(());
+    }
+
+    // 1) Get the outputs holding the reference tokens
+    let output_id_mapping = multiera_outputs
+        .iter()
+        .map(|output| ((output.tx_id, output.output_index as usize), output.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // 2) Get the reference tokens from the ones MultieraAssetMintTask added
+    let unique_pairs = queued_entries
+        .iter()
+        .map(|entry| (&entry.policy_id, &entry.asset_name))
+        .collect::<BTreeSet<_>>();
+
+    // https://github.com/dcSpark/carp/issues/46
+    let mut asset_conditions = Condition::any();
+    for (policy_id, asset_name) in unique_pairs.iter() {
+        asset_conditions = asset_conditions.add(
+            Condition::all()
+                .add(NativeAssetColumn::PolicyId.eq((*policy_id).clone()))
+                .add(NativeAssetColumn::AssetName.eq((*asset_name).clone())),
+        );
+    }
+    let found_assets = NativeAsset::find()
+        .filter(asset_conditions)
+        .all(db_tx)
+        .await?;
+    let asset_id_mapping = found_assets
+        .iter()
+        .map(|asset| ((&asset.policy_id, &asset.asset_name), asset.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // 3) Get the datum from the ones MultieraDatumTask added
+    let hash_to_id = PlutusDataHash::find()
+        .filter(
+            Condition::any().add(
+                PlutusDataHashColumn::Hash
+                    .is_in(queued_entries.iter().map(|entry| entry.datum_hash.to_vec())),
+            ),
+        )
+        .all(db_tx)
+        .await?
+        .drain(..)
+        .map(|entry| (entry.hash, entry.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // 4) Add the entries in the order they appear in the block so the latest one has the highest id
+    let to_add = queued_entries
+        .iter()
+        .map(|entry| {
+            let reference_asset_id =
+                match asset_id_mapping.get(&(&entry.policy_id, &entry.asset_name)) {
+                    Some(asset_id) => *asset_id,
+                    None => panic!(
+                        "Asset not found in database: {}.{}",
+                        hex::encode(&entry.policy_id),
+                        hex::encode(&entry.asset_name)
+                    ),
+                };
+            Cip68EntryActiveModel {
+                reference_asset_id: Set(reference_asset_id),
+                utxo_id: Set(output_id_mapping[&(entry.tx_id, entry.idx)]),
+                datum_id: Set(hash_to_id[entry.datum_hash.as_ref()]),
+                ..Default::default()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !to_add.is_empty() {
+        Cip68Entry::insert_many(to_add).exec(db_tx).await?;
+    }
+
+    Ok(())
+}
+
+fn queue_entries(
+    queued_entries: &mut Vec<QueuedEntry>,
+    tx_id: i64,
+    output: &MultiEraOutput,
+    idx: usize,
+) {
+    // CIP-68 metadata always has to be an inline datum
+    let datum_hash = match output.datum() {
+        Some(DatumOption::Data(datum)) => datum.compute_hash(),
+        _ => return,
+    };
+    for asset in output.non_ada_assets() {
+        if let Asset::NativeAsset(policy_id, asset_name, _) = asset {
+            if is_reference_token(&asset_name) {
+                queued_entries.push(QueuedEntry {
+                    tx_id,
+                    idx,
+                    policy_id: policy_id.to_vec(),
+                    asset_name,
+                    datum_hash,
+                });
+            }
+        }
+    }
+}
@@ -30,7 +30,9 @@ read [multiera_txs];
 write [];
 should_add_task |block, _properties| {
   block.1.txs().iter().any(|tx| {
-    !tx.plutus_data().is_empty() || tx.outputs().iter().any(|output| output.datum().is_some())
+    !tx.plutus_data().is_empty()
+      || tx.outputs().iter().any(|output| output.datum().is_some())
+      || tx.collateral_return().iter().any(|output| output.datum().is_some())
   })
 };
 execute |previous_data, task| handle_datum(
@@ -62,7 +64,11 @@ async fn handle_datum(
                 .entry(hash)
                 .or_insert_with(|| datum.encode_fragment().unwrap());
         }
-        for output in tx_body.outputs().iter() {
+        // the collateral return is only created if the tx fails
+        let collateral_return = tx_body
+            .collateral_return()
+            .filter(|_| !cardano_transaction.is_valid);
+        for output in tx_body.outputs().iter().chain(collateral_return.iter()) {
             match output.datum().as_ref() {
                 Some(DatumOption::Hash(hash)) => {
                     hash_to_tx
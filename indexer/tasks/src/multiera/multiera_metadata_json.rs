@@ -0,0 +1,68 @@
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::ledger::primitives::{alonzo::Metadatum, Fragment};
+
+use super::{
+    multiera_metadata::MultieraMetadataTask,
+    utils::metadata_json::{get_cip20_message, metadatum_to_json, CIP20_LABEL},
+};
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraMetadataJsonTask;
+  configuration EmptyConfig;
+  doc "Adds a JSON version (using the no schema mapping of cardano-cli) of every metadatum so it can be searched in the database.
+       Also extracts CIP-20 transaction messages into a text column with a full-text index";
+  era multiera;
+  dependencies [MultieraMetadataTask];
+  read [multiera_metadata];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.has_aux_data()
+  };
+  execute |previous_data, task| handle_metadata_json(
+      task.db_tx,
+      &previous_data.multiera_metadata,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_metadata_json(
+    db_tx: &DatabaseTransaction,
+    multiera_metadata: &[TransactionMetadataModel],
+) -> Result<(), DbErr> {
+    if multiera_metadata.is_empty() {
+        return Ok(());
+    }
+
+    let mut json_metadata = Vec::with_capacity(multiera_metadata.len());
+    for metadata in multiera_metadata.iter() {
+        let content = Metadatum::decode_fragment(metadata.payload.as_slice()).map_err(|e| {
+            DbErr::Custom(format!(
+                "failed to decode metadata {} as a metadatum: {}",
+                metadata.id, e
+            ))
+        })?;
+        let message = if metadata.label == CIP20_LABEL.to_le_bytes().to_vec() {
+            get_cip20_message(&content)
+        } else {
+            None
+        };
+        json_metadata.push(TransactionMetadataJsonActiveModel {
+            metadata_id: Set(metadata.id),
+            json: Set(metadatum_to_json(&content)),
+            message: Set(message),
+        });
+    }
+
+    TransactionMetadataJson::insert_many(json_metadata)
+        .exec(db_tx)
+        .await?;
+
+    Ok(())
+}
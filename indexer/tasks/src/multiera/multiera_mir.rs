@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use entity::{
+    move_instantaneous_reward::MirPotValue,
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::ledger::{
+    primitives::{
+        alonzo::{Certificate, InstantaneousRewardSource, InstantaneousRewardTarget},
+        Fragment,
+    },
+    traverse::MultiEraBlock,
+};
+
+use super::multiera_stake_credentials::MultieraStakeCredentialTask;
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraMirTask;
+  configuration EmptyConfig;
+  doc "Adds the move instantaneous rewards (MIR) certificates to the database with the amount given to each stake credential.
+       Transfers between the reserves and the treasury are added without a stake credential";
+  era multiera;
+  dependencies [MultieraStakeCredentialTask];
+  read [multiera_txs, multiera_stake_credential];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| tx.certs().iter().any(|cert| matches!(
+        cert.as_alonzo(),
+        Some(Certificate::MoveInstantaneousRewardsCert(..))
+    )))
+  };
+  execute |previous_data, task| handle_mir(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_stake_credential,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_mir(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_stake_credential: &BTreeMap<Vec<u8>, StakeCredentialModel>,
+) -> Result<(), DbErr> {
+    let mut queued_mir = Vec::<MoveInstantaneousRewardActiveModel>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        // certificates in a failed transaction never take effect
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        for cert in tx_body.certs() {
+            if let Certificate::MoveInstantaneousRewardsCert(mir) = cert.as_alonzo().unwrap() {
+                let pot = match mir.source {
+                    InstantaneousRewardSource::Reserves => MirPotValue::Reserves,
+                    InstantaneousRewardSource::Treasury => MirPotValue::Treasury,
+                };
+                match &mir.target {
+                    InstantaneousRewardTarget::StakeCredentials(credential_pairs) => {
+                        for (credential, amount) in credential_pairs.iter() {
+                            let credential = credential.encode_fragment().unwrap();
+                            queued_mir.push(MoveInstantaneousRewardActiveModel {
+                                tx_id: Set(cardano_transaction.id),
+                                pot: Set(pot.into()),
+                                credential_id: Set(Some(multiera_stake_credential[&credential].id)),
+                                amount: Set(*amount),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                    InstantaneousRewardTarget::OtherAccountingPot(amount) => {
+                        queued_mir.push(MoveInstantaneousRewardActiveModel {
+                            tx_id: Set(cardano_transaction.id),
+                            pot: Set(pot.into()),
+                            credential_id: Set(None),
+                            amount: Set(*amount as i64),
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    if !queued_mir.is_empty() {
+        MoveInstantaneousReward::insert_many(queued_mir)
+            .exec(db_tx)
+            .await?;
+    }
+
+    Ok(())
+}
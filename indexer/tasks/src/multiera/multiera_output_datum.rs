@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use super::{multiera_datum::MultieraDatumTask, multiera_used_outputs::MultieraOutputTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+    transaction_output_datum::OutputDatumKindValue,
+};
+use pallas::ledger::primitives::babbage::{DatumHash, DatumOption};
+use pallas::ledger::traverse::{ComputeHash, MultiEraBlock, MultiEraOutput};
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraOutputDatumTask;
+  configuration EmptyConfig;
+  doc "Links outputs to the datum they carry (either as a datum hash or as an inline datum)";
+  era multiera;
+  dependencies [MultieraOutputTask, MultieraDatumTask];
+  read [multiera_txs, multiera_outputs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| {
+        tx.outputs().iter().any(|output| output.datum().is_some())
+            || tx.collateral_return().iter().any(|output| output.datum().is_some())
+    })
+  };
+  execute |previous_data, task| handle_output_datum(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_outputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+struct QueuedOutputDatum {
+    tx_id: i64,
+    idx: usize,
+    hash: DatumHash,
+    kind: OutputDatumKindValue,
+}
+
+async fn handle_output_datum(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_outputs: &[TransactionOutputModel],
+) -> Result<(), DbErr> {
+    let mut queued_output_datum = Vec::<QueuedOutputDatum>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let outputs = tx_body.outputs();
+        if cardano_transaction.is_valid {
+            for (idx, output) in outputs.iter().enumerate() {
+                queue_output_datum(
+                    &mut queued_output_datum,
+                    cardano_transaction.id,
+                    output,
+                    idx,
+                );
+            }
+        }
+        if !cardano_transaction.is_valid {
+            if let Some(output) = tx_body.collateral_return().as_ref() {
+                queue_output_datum(
+                    &mut queued_output_datum,
+                    cardano_transaction.id,
+                    output,
+                    // only one collateral output is allowed
+                    // and its index is output.len()
+                    outputs.len(),
+                );
+            }
+        }
+    }
+
+    if queued_output_datum.is_empty() {
+        return Ok(());
+    }
+
+    let output_id_mapping = multiera_outputs
+        .iter()
+        .map(|output| ((output.tx_id, output.output_index as usize), output.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // all these hashes were added by MultieraDatumTask
+    let hash_to_id = PlutusDataHash::find()
+        .filter(
+            Condition::any().add(
+                PlutusDataHashColumn::Hash
+                    .is_in(queued_output_datum.iter().map(|entry| entry.hash.to_vec())),
+            ),
+        )
+        .all(db_tx)
+        .await?
+        .drain(..)
+        .map(|entry| (entry.hash, entry.id))
+        .collect::<BTreeMap<_, _>>();
+
+    let to_add = queued_output_datum
+        .iter()
+        .map(|entry| TransactionOutputDatumActiveModel {
+            utxo_id: Set(output_id_mapping[&(entry.tx_id, entry.idx)]),
+            datum_id: Set(hash_to_id[entry.hash.as_ref()]),
+            kind: Set(entry.kind.into()),
+        })
+        .collect::<Vec<_>>();
+
+    if !to_add.is_empty() {
+        TransactionOutputDatum::insert_many(to_add)
+            .exec(db_tx)
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn queue_output_datum(
+    queued_output_datum: &mut Vec<QueuedOutputDatum>,
+    tx_id: i64,
+    output: &MultiEraOutput,
+    idx: usize,
+) {
+    let (hash, kind) = match output.datum() {
+        Some(DatumOption::Hash(hash)) => (hash, OutputDatumKindValue::Hash),
+        Some(DatumOption::Data(datum)) => (datum.compute_hash(), OutputDatumKindValue::Inline),
+        None => return,
+    };
+    queued_output_datum.push(QueuedOutputDatum {
+        tx_id,
+        idx,
+        hash,
+        kind,
+    });
+}
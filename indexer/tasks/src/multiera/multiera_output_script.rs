@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use super::{multiera_script::MultieraScriptTask, multiera_used_outputs::MultieraOutputTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use cardano_multiplatform_lib::ScriptKind;
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::ledger::traverse::{MultiEraBlock, MultiEraOutput};
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraOutputScriptTask;
+  configuration EmptyConfig;
+  doc "Links outputs to the reference script they carry";
+  era multiera;
+  dependencies [MultieraOutputTask, MultieraScriptTask];
+  read [multiera_txs, multiera_outputs];
+  write [];
+  should_add_task |block, _properties| {
+    // reference scripts were introduced in Babbage
+    block.1.era() == pallas::ledger::traverse::Era::Babbage
+        && block.1.txs().iter().any(|tx| {
+            tx.outputs().iter().any(|output| script_ref_hash(output).is_some())
+                || tx.collateral_return().iter().any(|output| script_ref_hash(output).is_some())
+        })
+  };
+  execute |previous_data, task| handle_output_script(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_outputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_output_script(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_outputs: &[TransactionOutputModel],
+) -> Result<(), DbErr> {
+    let mut queued_output_script = Vec::<((i64, usize), Vec<u8>)>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let outputs = tx_body.outputs();
+        if cardano_transaction.is_valid {
+            for (idx, output) in outputs.iter().enumerate() {
+                if let Some(hash) = script_ref_hash(output) {
+                    queued_output_script.push(((cardano_transaction.id, idx), hash));
+                }
+            }
+        }
+        if !cardano_transaction.is_valid {
+            if let Some(output) = tx_body.collateral_return().as_ref() {
+                if let Some(hash) = script_ref_hash(output) {
+                    // only one collateral output is allowed
+                    // and its index is output.len()
+                    queued_output_script.push(((cardano_transaction.id, outputs.len()), hash));
+                }
+            }
+        }
+    }
+
+    if queued_output_script.is_empty() {
+        return Ok(());
+    }
+
+    let output_id_mapping = multiera_outputs
+        .iter()
+        .map(|output| ((output.tx_id, output.output_index as usize), output.id))
+        .collect::<BTreeMap<_, _>>();
+
+    // all these scripts were added by MultieraScriptTask
+    let hash_to_id = Script::find()
+        .filter(Condition::any().add(
+            ScriptColumn::Hash.is_in(queued_output_script.iter().map(|(_, hash)| hash.clone())),
+        ))
+        .all(db_tx)
+        .await?
+        .drain(..)
+        .map(|entry| (entry.hash, entry.id))
+        .collect::<BTreeMap<_, _>>();
+
+    TransactionOutputScript::insert_many(queued_output_script.iter().map(|(pointer, hash)| {
+        TransactionOutputScriptActiveModel {
+            utxo_id: Set(output_id_mapping[pointer]),
+            script_id: Set(hash_to_id[hash]),
+        }
+    }))
+    .exec(db_tx)
+    .await?;
+
+    Ok(())
+}
+
+fn script_ref_hash(output: &MultiEraOutput) -> Option<Vec<u8>> {
+    // https://github.com/dcSpark/cardano-multiplatform-lib/issues/61
+    let output = cardano_multiplatform_lib::TransactionOutput::from_bytes(output.encode()).ok()?;
+    let script = output.script_ref()?.script();
+    let hash = match script.kind() {
+        ScriptKind::NativeScript => script.as_native().unwrap().hash(),
+        ScriptKind::PlutusScriptV1 => script.as_plutus_v1().unwrap().hash(),
+        ScriptKind::PlutusScriptV2 => script.as_plutus_v2().unwrap().hash(),
+    };
+    Some(hash.to_bytes())
+}
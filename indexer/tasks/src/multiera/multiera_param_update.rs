@@ -0,0 +1,121 @@
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::ledger::primitives::Fragment;
+use pallas::ledger::traverse::{MultiEraBlock, MultiEraTx};
+
+use super::multiera_txs::MultieraTransactionTask;
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraParamUpdateTask;
+  configuration EmptyConfig;
+  doc "Adds the protocol parameter update proposals of each genesis delegate to the database.
+       The parameters in effect at each epoch are derived from these proposals into EpochParams when the epoch changes";
+  era multiera;
+  dependencies [MultieraTransactionTask];
+  read [multiera_txs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| has_update(tx))
+  };
+  execute |previous_data, task| handle_param_updates(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+// the fields have the same name in every era so the conversion is shared
+macro_rules! to_proposal {
+    ($tx_id:expr, $genesis_key:expr, $epoch:expr, $update:expr) => {
+        ParamProposalActiveModel {
+            tx_id: Set($tx_id),
+            genesis_key: Set($genesis_key.to_vec()),
+            epoch: Set($epoch as i32),
+            payload: Set($update.encode_fragment().unwrap()),
+            min_fee_a: Set($update.minfee_a.map(|x| x as i64)),
+            min_fee_b: Set($update.minfee_b.map(|x| x as i64)),
+            max_block_body_size: Set($update.max_block_body_size.map(|x| x as i32)),
+            max_tx_size: Set($update.max_transaction_size.map(|x| x as i32)),
+            max_block_header_size: Set($update.max_block_header_size.map(|x| x as i32)),
+            key_deposit: Set($update.key_deposit.map(|x| x as i64)),
+            pool_deposit: Set($update.pool_deposit.map(|x| x as i64)),
+            max_epoch: Set($update.maximum_epoch.map(|x| x as i32)),
+            optimal_pool_count: Set($update.desired_number_of_stake_pools.map(|x| x as i32)),
+            protocol_major: Set($update.protocol_version.map(|x| x.0 as i32)),
+            protocol_minor: Set($update.protocol_version.map(|x| x.1 as i32)),
+            min_pool_cost: Set($update.min_pool_cost.map(|x| x as i64)),
+            max_value_size: Set($update.max_value_size.map(|x| x as i32)),
+            collateral_percentage: Set($update.collateral_percentage.map(|x| x as i32)),
+            max_collateral_inputs: Set($update.max_collateral_inputs.map(|x| x as i32)),
+            ..Default::default()
+        }
+    };
+}
+
+fn has_update(tx: &MultiEraTx) -> bool {
+    match tx {
+        MultiEraTx::AlonzoCompatible(x, _) => x.transaction_body.update.is_some(),
+        MultiEraTx::Babbage(x) => x.transaction_body.update.is_some(),
+        _ => false,
+    }
+}
+
+fn get_proposals(tx: &MultiEraTx, tx_id: i64) -> Vec<ParamProposalActiveModel> {
+    match tx {
+        MultiEraTx::AlonzoCompatible(x, _) => x
+            .transaction_body
+            .update
+            .iter()
+            .flat_map(|update| {
+                update.proposed_protocol_parameter_updates.iter().map(
+                    move |(genesis_key, params)| {
+                        to_proposal!(tx_id, genesis_key, update.epoch, params)
+                    },
+                )
+            })
+            .collect(),
+        MultiEraTx::Babbage(x) => x
+            .transaction_body
+            .update
+            .iter()
+            .flat_map(|update| {
+                update.proposed_protocol_parameter_updates.iter().map(
+                    move |(genesis_key, params)| {
+                        to_proposal!(tx_id, genesis_key, update.epoch, params)
+                    },
+                )
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+async fn handle_param_updates(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+) -> Result<(), DbErr> {
+    let mut queued_proposals = Vec::<ParamProposalActiveModel>::default();
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        // proposals in a failed transaction never take effect
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        queued_proposals.extend(get_proposals(tx_body, cardano_transaction.id));
+    }
+
+    if !queued_proposals.is_empty() {
+        ParamProposal::insert_many(queued_proposals)
+            .exec(db_tx)
+            .await?;
+    }
+
+    Ok(())
+}
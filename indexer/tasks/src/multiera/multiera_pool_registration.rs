@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::ledger::{
+    primitives::{alonzo::Certificate, Fragment},
+    traverse::MultiEraBlock,
+};
+
+use super::multiera_txs::MultieraTransactionTask;
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraPoolRegistrationTask;
+  configuration EmptyConfig;
+  doc "Adds stake pools along with their registration parameters and retirements to the database.
+       The pool set at a block is every pool whose latest registration is before the block without a retirement epoch reached since";
+  era multiera;
+  dependencies [MultieraTransactionTask];
+  read [multiera_txs];
+  write [multiera_stake_pools];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| tx.certs().iter().any(|cert| matches!(
+        cert.as_alonzo(),
+        Some(Certificate::PoolRegistration { .. }) | Some(Certificate::PoolRetirement(..))
+    )))
+  };
+  execute |previous_data, task| handle_pool_registrations(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+  );
+  merge_result |previous_data, result| {
+    *previous_data.multiera_stake_pools = result;
+  };
+}
+
+struct QueuedRegistration {
+    tx_id: i64,
+    operator: Vec<u8>,
+    vrf_keyhash: Vec<u8>,
+    pledge: u64,
+    cost: u64,
+    margin: (u64, u64),
+    reward_address: Vec<u8>,
+    relays: Vec<u8>,
+    metadata: Option<(String, Vec<u8>)>,
+    payload: Vec<u8>,
+}
+
+struct QueuedRetirement {
+    tx_id: i64,
+    operator: Vec<u8>,
+    epoch: u64,
+}
+
+async fn handle_pool_registrations(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+) -> Result<BTreeMap<Vec<u8>, StakePoolModel>, DbErr> {
+    let mut queued_registrations = Vec::<QueuedRegistration>::default();
+    let mut queued_retirements = Vec::<QueuedRetirement>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        // certificates in a failed transaction never take effect
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        for cert in tx_body.certs() {
+            match cert.as_alonzo().unwrap() {
+                cert @ Certificate::PoolRegistration {
+                    operator,
+                    vrf_keyhash,
+                    pledge,
+                    cost,
+                    margin,
+                    reward_account,
+                    relays,
+                    pool_metadata,
+                    ..
+                } => {
+                    queued_registrations.push(QueuedRegistration {
+                        tx_id: cardano_transaction.id,
+                        operator: operator.to_vec(),
+                        vrf_keyhash: vrf_keyhash.to_vec(),
+                        pledge: *pledge,
+                        cost: *cost,
+                        margin: (margin.numerator, margin.denominator),
+                        reward_address: reward_account.to_vec(),
+                        relays: relays.encode_fragment().unwrap(),
+                        metadata: pool_metadata
+                            .as_ref()
+                            .map(|metadata| (metadata.url.clone(), metadata.hash.to_vec())),
+                        payload: cert.encode_fragment().unwrap(),
+                    });
+                }
+                Certificate::PoolRetirement(key_hash, epoch) => {
+                    queued_retirements.push(QueuedRetirement {
+                        tx_id: cardano_transaction.id,
+                        operator: key_hash.to_vec(),
+                        epoch: *epoch,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut queued_pools = BTreeMap::<Vec<u8>, i64>::default();
+    for (operator, tx_id) in queued_registrations
+        .iter()
+        .map(|reg| (&reg.operator, reg.tx_id))
+        .chain(
+            queued_retirements
+                .iter()
+                .map(|retirement| (&retirement.operator, retirement.tx_id)),
+        )
+    {
+        // we want to keep track of the first tx for each pool
+        queued_pools
+            .entry(operator.clone())
+            .and_modify(|old_id| {
+                if tx_id < *old_id {
+                    *old_id = tx_id
+                }
+            })
+            .or_insert(tx_id);
+    }
+
+    let pools = insert_stake_pools(&queued_pools, db_tx).await?;
+
+    if !queued_registrations.is_empty() {
+        PoolRegistration::insert_many(queued_registrations.iter().map(|reg| {
+            PoolRegistrationActiveModel {
+                pool_id: Set(pools[&reg.operator].id),
+                tx_id: Set(reg.tx_id),
+                vrf_keyhash: Set(reg.vrf_keyhash.clone()),
+                pledge: Set(reg.pledge as i64),
+                cost: Set(reg.cost as i64),
+                margin_numerator: Set(reg.margin.0 as i64),
+                margin_denominator: Set(reg.margin.1 as i64),
+                reward_address: Set(reg.reward_address.clone()),
+                relays: Set(reg.relays.clone()),
+                metadata_url: Set(reg.metadata.as_ref().map(|metadata| metadata.0.clone())),
+                metadata_hash: Set(reg.metadata.as_ref().map(|metadata| metadata.1.clone())),
+                payload: Set(reg.payload.clone()),
+                ..Default::default()
+            }
+        }))
+        .exec(db_tx)
+        .await?;
+    }
+
+    if !queued_retirements.is_empty() {
+        PoolRetirement::insert_many(queued_retirements.iter().map(|retirement| {
+            PoolRetirementActiveModel {
+                pool_id: Set(pools[&retirement.operator].id),
+                tx_id: Set(retirement.tx_id),
+                epoch: Set(retirement.epoch as i32),
+                ..Default::default()
+            }
+        }))
+        .exec(db_tx)
+        .await?;
+    }
+
+    Ok(pools)
+}
+
+pub async fn insert_stake_pools(
+    operators: &BTreeMap<Vec<u8>, i64>,
+    txn: &DatabaseTransaction,
+) -> Result<BTreeMap<Vec<u8>, StakePoolModel>, DbErr> {
+    let mut result_map = BTreeMap::<Vec<u8>, StakePoolModel>::default();
+
+    if operators.is_empty() {
+        return Ok(result_map);
+    }
+
+    // 1) Add pools that were already in the DB
+    {
+        let mut found_pools = StakePool::find()
+            .filter(
+                Condition::any().add(StakePoolColumn::Operator.is_in(operators.keys().cloned())),
+            )
+            .all(txn)
+            .await?;
+
+        result_map.extend(
+            found_pools
+                .drain(..)
+                .map(|model| (model.operator.clone(), model)),
+        );
+    }
+
+    // 2) Add pools that weren't in the DB
+    {
+        let mut pools_to_add: Vec<StakePoolActiveModel> = operators
+            .iter()
+            .filter(|&(operator, _)| !result_map.contains_key(operator))
+            .map(|(operator, tx_id)| StakePoolActiveModel {
+                operator: Set(operator.to_vec()),
+                first_tx: Set(*tx_id),
+                ..Default::default()
+            })
+            .collect();
+
+        // need to make sure we're inserting pools in the same order as we added txs
+        pools_to_add.sort_by(|a, b| a.first_tx.as_ref().cmp(b.first_tx.as_ref()));
+
+        if !pools_to_add.is_empty() {
+            let mut additions = StakePool::insert_many(pools_to_add)
+                .exec_many_with_returning(txn)
+                .await?;
+            additions.drain(..).for_each(|model| {
+                result_map.insert(model.operator.clone(), model);
+            });
+        }
+    }
+
+    Ok(result_map)
+}
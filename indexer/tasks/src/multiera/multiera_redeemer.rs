@@ -0,0 +1,206 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{multiera_datum::MultieraDatumTask, multiera_used_inputs::MultieraUsedInputTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use entity::{
+    prelude::*,
+    redeemer::RedeemerTagValue,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::ledger::primitives::{
+    alonzo::{Redeemer, RedeemerTag},
+    babbage::{DatumHash, DatumOption, TransactionOutput as BabbageOutput},
+    Fragment,
+};
+use pallas::ledger::traverse::{ComputeHash, MultiEraBlock, MultiEraTx};
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraRedeemerTask;
+  configuration EmptyConfig;
+  doc "Adds the redeemers (and their execution units) of every Plutus script execution.
+       Spend redeemers are linked to the input they consume and the datum of the output being spent";
+  era multiera;
+  dependencies [MultieraUsedInputTask, MultieraDatumTask];
+  read [multiera_txs, multiera_used_inputs];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| !get_redeemers(tx).is_empty())
+  };
+  execute |previous_data, task| handle_redeemers(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_used_inputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+pub fn get_redeemers<'b>(tx: &'b MultiEraTx) -> &'b [Redeemer] {
+    match tx {
+        MultiEraTx::AlonzoCompatible(x, _) => x
+            .transaction_witness_set
+            .redeemer
+            .as_ref()
+            .map(|redeemers| redeemers.as_slice())
+            .unwrap_or_default(),
+        MultiEraTx::Babbage(x) => x
+            .transaction_witness_set
+            .redeemer
+            .as_ref()
+            .map(|redeemers| redeemers.as_slice())
+            .unwrap_or_default(),
+        _ => &[],
+    }
+}
+
+fn to_tag_value(tag: &RedeemerTag) -> RedeemerTagValue {
+    match tag {
+        RedeemerTag::Spend => RedeemerTagValue::Spend,
+        RedeemerTag::Mint => RedeemerTagValue::Mint,
+        RedeemerTag::Cert => RedeemerTagValue::Cert,
+        RedeemerTag::Reward => RedeemerTagValue::Reward,
+    }
+}
+
+fn datum_hash_of_output(payload: &[u8]) -> Option<DatumHash> {
+    // note: Byron outputs can't be locked by a script, so failing to decode is fine
+    match BabbageOutput::decode_fragment(payload).ok()? {
+        BabbageOutput::Legacy(output) => output.datum_hash,
+        BabbageOutput::PostAlonzo(output) => match output.datum_option {
+            Some(DatumOption::Hash(hash)) => Some(hash),
+            Some(DatumOption::Data(datum)) => Some(datum.compute_hash()),
+            None => None,
+        },
+    }
+}
+
+struct QueuedRedeemer {
+    tx_id: i64,
+    tag: RedeemerTagValue,
+    index: u32,
+    payload: Vec<u8>,
+    ex_units_mem: u64,
+    ex_units_steps: u64,
+    input: Option<TransactionInputModel>,
+}
+
+async fn handle_redeemers(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_used_inputs: &[TransactionInputModel],
+) -> Result<(), DbErr> {
+    let mut inputs_by_tx = BTreeMap::<i64, BTreeMap<i32, &TransactionInputModel>>::default();
+    for input in multiera_used_inputs {
+        inputs_by_tx
+            .entry(input.tx_id)
+            .or_default()
+            .insert(input.input_index, input);
+    }
+
+    let mut queued_redeemers = Vec::<QueuedRedeemer>::default();
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let redeemers = get_redeemers(tx_body);
+        if redeemers.is_empty() {
+            continue;
+        }
+
+        // recall: spend redeemers point to the index of the input in the sorted list of inputs
+        let inputs = tx_body
+            .inputs()
+            .iter()
+            .map(|input| input.output_ref())
+            .collect::<Vec<_>>();
+        let mut sorted_inputs = inputs.iter().enumerate().collect::<Vec<_>>();
+        sorted_inputs.sort_by(|(_, a), (_, b)| (a.hash(), a.index()).cmp(&(b.hash(), b.index())));
+
+        for redeemer in redeemers {
+            let tag = to_tag_value(&redeemer.tag);
+            let input = match (tag, cardano_transaction.is_valid) {
+                // if the tx fails, the regular inputs aren't consumed (the collateral is instead)
+                (RedeemerTagValue::Spend, true) => sorted_inputs
+                    .get(redeemer.index as usize)
+                    .and_then(|(body_index, _)| {
+                        inputs_by_tx
+                            .get(&cardano_transaction.id)
+                            .and_then(|inputs| inputs.get(&(*body_index as i32)))
+                    })
+                    .map(|&input| input.clone()),
+                _ => None,
+            };
+            queued_redeemers.push(QueuedRedeemer {
+                tx_id: cardano_transaction.id,
+                tag,
+                index: redeemer.index,
+                payload: redeemer.data.encode_fragment().unwrap(),
+                ex_units_mem: redeemer.ex_units.mem as u64,
+                ex_units_steps: redeemer.ex_units.steps as u64,
+                input,
+            });
+        }
+    }
+
+    if queued_redeemers.is_empty() {
+        return Ok(());
+    }
+
+    // 1) Get the datum hash of the outputs being spent
+    let spent_utxo_ids = queued_redeemers
+        .iter()
+        .filter_map(|redeemer| redeemer.input.as_ref().map(|input| input.utxo_id))
+        .collect::<BTreeSet<_>>();
+    let mut utxo_to_datum = BTreeMap::<i64, DatumHash>::default();
+    if !spent_utxo_ids.is_empty() {
+        let spent_outputs = TransactionOutput::find()
+            .filter(
+                Condition::any().add(TransactionOutputColumn::Id.is_in(spent_utxo_ids.into_iter())),
+            )
+            .all(db_tx)
+            .await?;
+        for output in spent_outputs {
+            if let Some(hash) = datum_hash_of_output(&output.payload) {
+                utxo_to_datum.insert(output.id, hash);
+            }
+        }
+    }
+
+    // 2) Get the ids of these datum hashes (added by MultieraDatumTask)
+    let mut datum_to_id = BTreeMap::<Vec<u8>, i64>::default();
+    if !utxo_to_datum.is_empty() {
+        let found_hashes = PlutusDataHash::find()
+            .filter(Condition::any().add(
+                PlutusDataHashColumn::Hash.is_in(utxo_to_datum.values().map(|hash| hash.to_vec())),
+            ))
+            .all(db_tx)
+            .await?;
+        datum_to_id.extend(found_hashes.into_iter().map(|entry| (entry.hash, entry.id)));
+    }
+
+    // 3) Add the redeemers
+    Redeemer::insert_many(queued_redeemers.iter().map(|redeemer| {
+        let datum_id = redeemer
+            .input
+            .as_ref()
+            .and_then(|input| utxo_to_datum.get(&input.utxo_id))
+            .and_then(|hash| datum_to_id.get(hash.as_ref()))
+            .copied();
+        RedeemerActiveModel {
+            tx_id: Set(redeemer.tx_id),
+            tag: Set(redeemer.tag.into()),
+            index: Set(redeemer.index as i32),
+            payload: Set(redeemer.payload.clone()),
+            ex_units_mem: Set(redeemer.ex_units_mem as i64),
+            ex_units_steps: Set(redeemer.ex_units_steps as i64),
+            datum_id: Set(datum_id),
+            input_id: Set(redeemer.input.as_ref().map(|input| input.id)),
+            ..Default::default()
+        }
+    }))
+    .exec(db_tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+
+use super::{multiera_stake_credentials::to_witness_cbor, multiera_txs::MultieraTransactionTask};
+use crate::config::EmptyConfig::EmptyConfig;
+use cardano_multiplatform_lib::{crypto::ScriptHash, NativeScript, ScriptKind};
+use entity::{
+    prelude::*,
+    script::ScriptKindValue,
+    sea_orm::{prelude::*, Condition, DatabaseTransaction, Set},
+};
+use pallas::codec::utils::Nullable;
+use pallas::ledger::primitives::Fragment;
+use pallas::ledger::traverse::{MultiEraBlock, MultiEraTx};
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraScriptTask;
+  configuration EmptyConfig;
+  doc "Adds the native and Plutus scripts found in witness sets, auxiliary data and reference scripts of outputs";
+  era multiera;
+  dependencies [MultieraTransactionTask];
+  read [multiera_txs];
+  write [];
+  should_add_task |block, _properties| {
+    !block.1.is_empty()
+  };
+  execute |previous_data, task| handle_scripts(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+struct QueuedScript {
+    kind: ScriptKindValue,
+    payload: Vec<u8>,
+    first_tx: i64,
+}
+
+async fn handle_scripts(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+) -> Result<(), DbErr> {
+    let mut queued_scripts = BTreeMap::<Vec<u8>, QueuedScript>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let tx_id = cardano_transaction.id;
+
+        // 1) scripts in the witness set
+        let witness_set =
+            cardano_multiplatform_lib::TransactionWitnessSet::from_bytes(to_witness_cbor(tx_body))
+                .unwrap();
+        if let Some(scripts) = witness_set.native_scripts() {
+            for i in 0..scripts.len() {
+                queue_native_script(&mut queued_scripts, tx_id, &scripts.get(i));
+            }
+        }
+        if let Some(scripts) = witness_set.plutus_v1_scripts() {
+            for i in 0..scripts.len() {
+                let script = scripts.get(i);
+                queue_plutus_script(
+                    &mut queued_scripts,
+                    tx_id,
+                    &script.hash(),
+                    script.to_bytes(),
+                    ScriptKindValue::PlutusV1,
+                );
+            }
+        }
+        if let Some(scripts) = witness_set.plutus_v2_scripts() {
+            for i in 0..scripts.len() {
+                let script = scripts.get(i);
+                queue_plutus_script(
+                    &mut queued_scripts,
+                    tx_id,
+                    &script.hash(),
+                    script.to_bytes(),
+                    ScriptKindValue::PlutusV2,
+                );
+            }
+        }
+
+        // 2) scripts in the auxiliary data
+        let aux_data = to_aux_data_cbor(tx_body).and_then(|aux_data| {
+            match cardano_multiplatform_lib::metadata::AuxiliaryData::from_bytes(aux_data) {
+                Ok(aux_data) => Some(aux_data),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to decode the auxiliary data of tx {}: {}",
+                        hex::encode(tx_body.hash()),
+                        e
+                    );
+                    None
+                }
+            }
+        });
+        if let Some(aux_data) = aux_data {
+            if let Some(scripts) = aux_data.native_scripts() {
+                for i in 0..scripts.len() {
+                    queue_native_script(&mut queued_scripts, tx_id, &scripts.get(i));
+                }
+            }
+            if let Some(scripts) = aux_data.plutus_v1_scripts() {
+                for i in 0..scripts.len() {
+                    let script = scripts.get(i);
+                    queue_plutus_script(
+                        &mut queued_scripts,
+                        tx_id,
+                        &script.hash(),
+                        script.to_bytes(),
+                        ScriptKindValue::PlutusV1,
+                    );
+                }
+            }
+            if let Some(scripts) = aux_data.plutus_v2_scripts() {
+                for i in 0..scripts.len() {
+                    let script = scripts.get(i);
+                    queue_plutus_script(
+                        &mut queued_scripts,
+                        tx_id,
+                        &script.hash(),
+                        script.to_bytes(),
+                        ScriptKindValue::PlutusV2,
+                    );
+                }
+            }
+        }
+
+        // 3) reference scripts in the outputs
+        // note: reference scripts are stored even if the tx fails
+        // since a script may be referenced by a hash in an address regardless
+        for output in tx_body
+            .outputs()
+            .iter()
+            .chain(tx_body.collateral_return().iter())
+        {
+            let output =
+                match cardano_multiplatform_lib::TransactionOutput::from_bytes(output.encode()) {
+                    Ok(output) => output,
+                    // https://github.com/dcSpark/cardano-multiplatform-lib/issues/61
+                    Err(_e) => continue,
+                };
+            if let Some(script_ref) = output.script_ref() {
+                queue_script(&mut queued_scripts, tx_id, &script_ref.script());
+            }
+        }
+    }
+
+    if queued_scripts.is_empty() {
+        return Ok(());
+    }
+
+    // 1) Check which scripts were already in the DB
+    let found_scripts = Script::find()
+        .filter(Condition::any().add(ScriptColumn::Hash.is_in(queued_scripts.keys().cloned())))
+        .all(db_tx)
+        .await?;
+    for script in found_scripts.iter() {
+        queued_scripts.remove(&script.hash);
+    }
+
+    // 2) Add scripts that weren't in the DB
+    let mut scripts_to_add = queued_scripts
+        .into_iter()
+        .map(|(hash, script)| ScriptActiveModel {
+            hash: Set(hash),
+            kind: Set(script.kind.into()),
+            payload: Set(script.payload),
+            first_tx: Set(script.first_tx),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    // need to make sure we're inserting scripts in the same order as we added txs
+    scripts_to_add.sort_by(|a, b| a.first_tx.as_ref().cmp(b.first_tx.as_ref()));
+
+    if !scripts_to_add.is_empty() {
+        Script::insert_many(scripts_to_add).exec(db_tx).await?;
+    }
+
+    Ok(())
+}
+
+fn to_aux_data_cbor(tx: &MultiEraTx) -> Option<Vec<u8>> {
+    match tx {
+        MultiEraTx::AlonzoCompatible(x, _) => match &x.auxiliary_data {
+            Nullable::Some(aux_data) => Some(aux_data.encode_fragment().unwrap()),
+            _ => None,
+        },
+        MultiEraTx::Babbage(x) => match &x.auxiliary_data {
+            Nullable::Some(aux_data) => Some(aux_data.encode_fragment().unwrap()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn queue_script(
+    queued_scripts: &mut BTreeMap<Vec<u8>, QueuedScript>,
+    tx_id: i64,
+    script: &cardano_multiplatform_lib::Script,
+) {
+    match script.kind() {
+        ScriptKind::NativeScript => {
+            queue_native_script(queued_scripts, tx_id, &script.as_native().unwrap())
+        }
+        ScriptKind::PlutusScriptV1 => {
+            let script = script.as_plutus_v1().unwrap();
+            queue_plutus_script(
+                queued_scripts,
+                tx_id,
+                &script.hash(),
+                script.to_bytes(),
+                ScriptKindValue::PlutusV1,
+            )
+        }
+        ScriptKind::PlutusScriptV2 => {
+            let script = script.as_plutus_v2().unwrap();
+            queue_plutus_script(
+                queued_scripts,
+                tx_id,
+                &script.hash(),
+                script.to_bytes(),
+                ScriptKindValue::PlutusV2,
+            )
+        }
+    }
+}
+
+fn queue_native_script(
+    queued_scripts: &mut BTreeMap<Vec<u8>, QueuedScript>,
+    tx_id: i64,
+    script: &NativeScript,
+) {
+    add_to_queue(
+        queued_scripts,
+        &script.hash(),
+        QueuedScript {
+            kind: ScriptKindValue::Native,
+            payload: script.to_bytes(),
+            first_tx: tx_id,
+        },
+    );
+}
+
+fn queue_plutus_script(
+    queued_scripts: &mut BTreeMap<Vec<u8>, QueuedScript>,
+    tx_id: i64,
+    hash: &ScriptHash,
+    payload: Vec<u8>,
+    kind: ScriptKindValue,
+) {
+    add_to_queue(
+        queued_scripts,
+        hash,
+        QueuedScript {
+            kind,
+            payload,
+            first_tx: tx_id,
+        },
+    );
+}
+
+fn add_to_queue(
+    queued_scripts: &mut BTreeMap<Vec<u8>, QueuedScript>,
+    hash: &ScriptHash,
+    script: QueuedScript,
+) {
+    // we want to keep track of the first tx for each script
+    queued_scripts.entry(hash.to_bytes()).or_insert(script);
+}
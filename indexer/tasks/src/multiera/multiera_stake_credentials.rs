@@ -98,7 +98,7 @@ async fn handle_stake_credentials(
     Ok(cred_to_model_map)
 }
 
-async fn insert_stake_credentials(
+pub async fn insert_stake_credentials(
     deduplicated_creds: &BTreeMap<Vec<u8>, i64>,
     txn: &DatabaseTransaction,
 ) -> Result<BTreeMap<Vec<u8>, StakeCredentialModel>, DbErr> {
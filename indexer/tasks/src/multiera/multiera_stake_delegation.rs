@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::ledger::{
+    primitives::{alonzo::Certificate, Fragment},
+    traverse::MultiEraBlock,
+};
+
+use super::multiera_stake_credentials::MultieraStakeCredentialTask;
+use crate::config::EmptyConfig::EmptyConfig;
+
+use crate::dsl::task_macro::*;
+
+carp_task! {
+  name MultieraDelegationTask;
+  configuration EmptyConfig;
+  doc "Adds the history of stake delegations to the database, keyed by stake credential and the epoch of the block the delegation was made in";
+  era multiera;
+  dependencies [MultieraStakeCredentialTask];
+  read [multiera_txs, multiera_stake_credential];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| tx.certs().iter().any(|cert| matches!(
+        cert.as_alonzo(),
+        Some(Certificate::StakeDelegation(..))
+    )))
+  };
+  execute |previous_data, task| handle_stake_delegation(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_stake_credential,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_stake_delegation(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    multiera_stake_credential: &BTreeMap<Vec<u8>, StakeCredentialModel>,
+) -> Result<(), DbErr> {
+    let epoch = block.2.epoch.unwrap() as i32;
+
+    let mut queued_delegations = Vec::<StakeDelegationActiveModel>::default();
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        // certificates in a failed transaction never take effect
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        for cert in tx_body.certs() {
+            if let Certificate::StakeDelegation(credential, pool) = cert.as_alonzo().unwrap() {
+                let credential = credential.encode_fragment().unwrap();
+                queued_delegations.push(StakeDelegationActiveModel {
+                    credential_id: Set(multiera_stake_credential[&credential].id),
+                    pool_credential: Set(pool.to_vec()),
+                    tx_id: Set(cardano_transaction.id),
+                    epoch: Set(epoch),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    if !queued_delegations.is_empty() {
+        StakeDelegation::insert_many(queued_delegations)
+            .exec(db_tx)
+            .await?;
+    }
+
+    Ok(())
+}
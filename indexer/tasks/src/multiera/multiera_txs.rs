@@ -7,7 +7,7 @@ use crate::era_common::transactions_from_hashes;
 use entity::sea_orm::{DatabaseTransaction, QueryOrder, Set};
 use pallas::ledger::primitives::alonzo::{self};
 use pallas::ledger::primitives::Fragment;
-use pallas::ledger::traverse::MultiEraBlock;
+use pallas::ledger::traverse::{MultiEraBlock, MultiEraTx};
 
 carp_task! {
   name MultieraTransactionTask;
@@ -57,13 +57,21 @@ async fn handle_tx(
         .txs()
         .iter()
         .enumerate()
-        .map(|(idx, tx)| TransactionActiveModel {
-            hash: Set(tx.hash().to_vec()),
-            block_id: Set(database_block.id),
-            tx_index: Set(idx as i32),
-            payload: Set(tx.encode()),
-            is_valid: Set(tx.is_valid()),
-            ..Default::default()
+        .map(|(idx, tx)| {
+            let payload = tx.encode();
+            let body_values = get_body_values(tx);
+            TransactionActiveModel {
+                hash: Set(tx.hash().to_vec()),
+                block_id: Set(database_block.id),
+                tx_index: Set(idx as i32),
+                size: Set(Some(payload.len() as i32)),
+                payload: Set(payload),
+                is_valid: Set(tx.is_valid()),
+                fee: Set(body_values.fee.map(|fee| fee as i64)),
+                ttl: Set(body_values.ttl.map(|ttl| ttl as i64)),
+                validity_start: Set(body_values.validity_start.map(|start| start as i64)),
+                ..Default::default()
+            }
         })
         .collect();
 
@@ -76,3 +84,30 @@ async fn handle_tx(
         Ok(vec![])
     }
 }
+
+pub struct BodyValues {
+    // failed txs pay with their collateral instead of the fee
+    // so this is None if the collateral has to be computed from the inputs it spends
+    pub fee: Option<u64>,
+    pub ttl: Option<u64>,
+    pub validity_start: Option<u64>,
+}
+
+pub fn get_body_values(tx: &MultiEraTx) -> BodyValues {
+    match tx {
+        MultiEraTx::AlonzoCompatible(x, _) => BodyValues {
+            fee: tx.is_valid().then_some(x.transaction_body.fee),
+            ttl: x.transaction_body.ttl,
+            validity_start: x.transaction_body.validity_interval_start,
+        },
+        MultiEraTx::Babbage(x) => BodyValues {
+            fee: match tx.is_valid() {
+                true => Some(x.transaction_body.fee),
+                false => x.transaction_body.total_collateral,
+            },
+            ttl: x.transaction_body.ttl,
+            validity_start: x.transaction_body.validity_interval_start,
+        },
+        _ => panic!("get_body_values - Unhandled tx type"),
+    }
+}
@@ -11,9 +11,15 @@ use entity::{
     prelude::*,
     sea_orm::{prelude::*, DatabaseTransaction},
 };
+use pallas::ledger::primitives::{
+    alonzo::Value, babbage::TransactionOutput as BabbageOutput, byron, Fragment,
+};
 use pallas::ledger::traverse::{MultiEraBlock, MultiEraInput, OutputRef};
 
-use super::{multiera_used_outputs::MultieraOutputTask, relation_map::RelationMap};
+use super::{
+    multiera_txs::get_body_values, multiera_used_outputs::MultieraOutputTask,
+    relation_map::RelationMap,
+};
 
 use crate::dsl::task_macro::*;
 
@@ -92,6 +98,9 @@ async fn handle_input(
                 TxCredentialRelationValue::Input,
                 TxCredentialRelationValue::InputStake,
             );
+            if !readonly {
+                update_collateral_fees(db_tx, block, multiera_txs, &input_to_output_map).await?;
+            }
             if readonly {
                 Ok(input_from_pointer(
                     db_tx,
@@ -113,6 +122,52 @@ async fn handle_input(
     }
 }
 
+/// Failed txs pay with their collateral (minus the collateral return) instead of their fee
+/// When the tx doesn't specify its total collateral, it is computed from the inputs it spends
+pub async fn update_collateral_fees(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    input_to_output_map: &BTreeMap<&Vec<u8>, BTreeMap<i64, &TransactionOutputModel>>,
+) -> Result<(), DbErr> {
+    let mut fees = Vec::<(i64, u64)>::default();
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        if tx_body.is_valid() || get_body_values(tx_body).fee.is_some() {
+            continue;
+        }
+        let mut collateral_sum = 0;
+        for input in tx_body.collateral().iter() {
+            let output_ref = input.output_ref();
+            let output =
+                input_to_output_map[&output_ref.hash().to_vec()][&(output_ref.index() as i64)];
+            collateral_sum += output_ada_amount(output)?;
+        }
+        let collateral_return = tx_body
+            .collateral_return()
+            .as_ref()
+            .map_or(0, |output| output.ada_amount());
+        fees.push((cardano_transaction.id, collateral_sum - collateral_return));
+    }
+
+    crate::era_common::update_transaction_fees(db_tx, &fees).await
+}
+
+fn output_ada_amount(output: &TransactionOutputModel) -> Result<u64, DbErr> {
+    // note: collateral can spend outputs created back in Byron
+    if let Ok(byron_output) = byron::TxOut::decode_fragment(&output.payload) {
+        return Ok(byron_output.amount);
+    }
+    let value = match BabbageOutput::decode_fragment(&output.payload)
+        .map_err(|e| DbErr::Custom(format!("failed to decode output {}: {}", output.id, e)))?
+    {
+        BabbageOutput::Legacy(output) => output.amount,
+        BabbageOutput::PostAlonzo(output) => output.value,
+    };
+    match value {
+        Value::Coin(coin) | Value::Multiasset(coin, _) => Ok(coin),
+    }
+}
+
 pub fn add_input_relations(
     vkey_relation_map: &mut RelationMap,
     inputs: &[(Vec<OutputRef>, i64)],
@@ -46,6 +46,7 @@ struct QueuedOutput {
     idx: usize,
     payload: Vec<u8>,
     address: Vec<u8>, // pallas::crypto::hash::Hash<32>
+    amount: u64,
 }
 
 async fn handle_output(
@@ -115,6 +116,7 @@ fn queue_output(
     queued_output.push(QueuedOutput {
         payload: output.encode(),
         address: addr.to_vec(),
+        amount: output.ada_amount(),
         tx_id,
         idx,
     });
@@ -139,6 +141,7 @@ async fn insert_outputs(
             tx_id: Set(entry.tx_id),
             payload: Set(entry.payload.clone()),
             output_index: Set(entry.idx as i32),
+            amount: Set(Some(entry.amount as i64)),
             ..Default::default()
         }
     }))
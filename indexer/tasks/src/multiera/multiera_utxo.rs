@@ -0,0 +1,37 @@
+use super::multiera_used_inputs::MultieraUsedInputTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::era_common::{delete_spent_utxos, insert_utxos};
+
+carp_task! {
+  name MultieraUtxoTask;
+  configuration EmptyConfig;
+  doc "Keeps the live UTXO set in sync by adding the outputs and removing the spent inputs of the block.
+       For failed transactions, this is the collateral return and the collateral inputs respectively.
+       Note: this task has to be enabled since genesis for the UTXO set to be complete";
+  era multiera;
+  dependencies [MultieraUsedInputTask];
+  read [multiera_outputs, multiera_used_inputs];
+  write [];
+  should_add_task |block, _properties| {
+    // txs always have at least one input (even if tx fails)
+    !block.1.is_empty()
+  };
+  execute |previous_data, task| handle_utxos(
+      task.db_tx,
+      &previous_data.multiera_outputs,
+      &previous_data.multiera_used_inputs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_utxos(
+    db_tx: &DatabaseTransaction,
+    outputs: &[TransactionOutputModel],
+    inputs: &[TransactionInputModel],
+) -> Result<(), DbErr> {
+    // note: outputs have to be added first since they may be spent within the same block
+    insert_utxos(outputs, db_tx).await?;
+    delete_spent_utxos(inputs, db_tx).await
+}
@@ -0,0 +1,84 @@
+use super::multiera_txs::{get_body_values, MultieraTransactionTask};
+use super::multiera_used_inputs::update_collateral_fees;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::era_common::{update_output_amounts, update_transaction_values};
+use pallas::ledger::traverse::MultiEraBlock;
+
+carp_task! {
+  name MultieraValueBackfillTask;
+  configuration EmptyConfig;
+  doc "Fills in the fee, ttl, validity start, size and output amounts of transactions that were added before these columns existed.
+       Only meant to be used with MultieraTransactionTask in readonly mode (see the value_backfill execution plan)";
+  era multiera;
+  dependencies [MultieraTransactionTask];
+  read [multiera_txs];
+  write [];
+  should_add_task |block, _properties| {
+    !block.1.is_empty()
+  };
+  execute |previous_data, task| handle_backfill(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_backfill(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+) -> Result<(), DbErr> {
+    let mut tx_values = Vec::<(i64, Option<u64>, Option<u64>, Option<u64>, i32)>::default();
+    let mut amounts = Vec::<(i64, usize, u64)>::default();
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        let body_values = get_body_values(tx_body);
+        tx_values.push((
+            cardano_transaction.id,
+            body_values.fee,
+            body_values.ttl,
+            body_values.validity_start,
+            cardano_transaction.payload.len() as i32,
+        ));
+
+        let outputs = tx_body.outputs();
+        if cardano_transaction.is_valid {
+            for (idx, output) in outputs.iter().enumerate() {
+                amounts.push((cardano_transaction.id, idx, output.ada_amount()));
+            }
+        }
+        if !cardano_transaction.is_valid {
+            if let Some(output) = tx_body.collateral_return().as_ref() {
+                // only one collateral output is allowed
+                // and its index is output.len()
+                amounts.push((cardano_transaction.id, outputs.len(), output.ada_amount()));
+            }
+        }
+    }
+
+    update_transaction_values(db_tx, &tx_values).await?;
+    update_output_amounts(db_tx, &amounts).await?;
+
+    // the collateral of failed txs may have to be computed from the inputs it spends
+    let collateral_inputs: Vec<(Vec<pallas::ledger::traverse::OutputRef>, i64)> = block
+        .1
+        .txs()
+        .iter()
+        .zip(multiera_txs)
+        .filter(|(tx_body, _)| get_body_values(tx_body).fee.is_none())
+        .map(|(tx_body, cardano_transaction)| {
+            let inputs = tx_body
+                .collateral()
+                .iter()
+                .map(|x| x.output_ref())
+                .collect();
+            (inputs, cardano_transaction.id)
+        })
+        .collect();
+    let outputs_for_inputs =
+        crate::era_common::get_outputs_for_inputs(&collateral_inputs, db_tx).await?;
+    let input_to_output_map = crate::era_common::gen_input_to_output_map(&outputs_for_inputs);
+    update_collateral_fees(db_tx, block, multiera_txs, &input_to_output_map).await
+}
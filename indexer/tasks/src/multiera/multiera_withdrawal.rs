@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use super::multiera_address::MultieraAddressTask;
+use crate::config::EmptyConfig::EmptyConfig;
+use crate::dsl::task_macro::*;
+use crate::era_common::get_truncated_address;
+use entity::{
+    prelude::*,
+    sea_orm::{prelude::*, DatabaseTransaction, Set},
+};
+use pallas::ledger::traverse::MultiEraBlock;
+
+carp_task! {
+  name MultieraWithdrawalTask;
+  configuration EmptyConfig;
+  doc "Adds the amount of rewards withdrawn from each reward address";
+  era multiera;
+  dependencies [MultieraAddressTask];
+  read [multiera_txs, multiera_addresses];
+  write [];
+  should_add_task |block, _properties| {
+    block.1.txs().iter().any(|tx| tx.withdrawals().collect::<Vec<(&[u8], u64)>>().len() > 0)
+  };
+  execute |previous_data, task| handle_withdrawals(
+      task.db_tx,
+      task.block,
+      &previous_data.multiera_txs,
+      &previous_data.multiera_addresses,
+  );
+  merge_result |previous_data, _result| {
+  };
+}
+
+async fn handle_withdrawals(
+    db_tx: &DatabaseTransaction,
+    block: BlockInfo<'_, MultiEraBlock<'_>>,
+    multiera_txs: &[TransactionModel],
+    addresses: &BTreeMap<Vec<u8>, AddressInBlock>,
+) -> Result<(), DbErr> {
+    let mut queued_withdrawals = Vec::<WithdrawalActiveModel>::default();
+
+    for (tx_body, cardano_transaction) in block.1.txs().iter().zip(multiera_txs) {
+        // rewards are only withdrawn if the tx succeeds
+        if !cardano_transaction.is_valid {
+            continue;
+        }
+        for (reward_address, amount) in tx_body.withdrawals().collect::<Vec<(&[u8], u64)>>() {
+            queued_withdrawals.push(WithdrawalActiveModel {
+                tx_id: Set(cardano_transaction.id),
+                address_id: Set(addresses
+                    .get(get_truncated_address(reward_address))
+                    .unwrap()
+                    .model
+                    .id),
+                amount: Set(amount as i64),
+            });
+        }
+    }
+
+    if !queued_withdrawals.is_empty() {
+        Withdrawal::insert_many(queued_withdrawals)
+            .exec(db_tx)
+            .await?;
+    }
+
+    Ok(())
+}
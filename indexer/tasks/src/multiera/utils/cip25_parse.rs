@@ -1,39 +1,84 @@
 // Note: this is taken from https://github.com/txpipe/oura/blob/393d47484cf87423e6f54e224c656f236159085d/src/mapper/cip25.rs
 // We should instead at some point have a proper cip25_rs
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 
-use cardano_multiplatform_lib::crypto::ScriptHash;
 use pallas::ledger::primitives::{alonzo::Metadatum, Fragment};
 
+use super::metadata_json::{metadatum_to_json, strip_nul};
 use super::user_asset::{AssetName, Cip25ParseError, Payload, PolicyId};
 
-// Heuristic approach for filtering policy entries. This is the best I could
-// come up with. Is there a better, official way?
-fn is_policy_key(key: &Metadatum) -> Option<PolicyId> {
+/// The standard fields of a CIP-25 asset entry
+#[derive(Debug, Default, Clone)]
+pub struct Cip25Fields {
+    pub name: Option<String>,
+    pub image: Option<String>,
+    pub media_type: Option<String>,
+    pub description: Option<String>,
+    pub files: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cip25Asset {
+    pub payload: Payload,
+    pub fields: Cip25Fields,
+}
+
+/// An entry of the 721 label that couldn't be parsed
+/// policy_id and asset_name are set when the failure could be narrowed down to them
+#[derive(Debug, Clone)]
+pub struct Cip25Rejection {
+    pub policy_id: Option<PolicyId>,
+    pub asset_name: Option<AssetName>,
+    pub reason: Cip25ParseError,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Cip25Pairs {
+    pub version: String,
+    pub assets: BTreeMap<PolicyId, BTreeMap<AssetName, Cip25Asset>>,
+    pub rejections: Vec<Cip25Rejection>,
+}
+
+const VERSION_KEY: &str = "version";
+
+// v1 is supposed to use text keys (hex-encoded policy and utf8 asset name)
+// and v2 the raw bytes for both, but some v1 metadata on-chain uses bytes keys
+// so both are accepted regardless of the version
+fn is_policy_key(key: &Metadatum) -> Result<PolicyId, Cip25ParseError> {
     match key {
-        Metadatum::Bytes(x) if x.len() == 28 => Some(x.to_vec()),
-        Metadatum::Text(x) if x.len() == 56 => hex::decode(x).ok(),
-        _ => None,
+        Metadatum::Bytes(x) if x.len() == 28 => Ok(x.to_vec()),
+        Metadatum::Text(x) if x.len() == 56 => hex::decode(x)
+            .map_err(|_| Cip25ParseError(format!("policy key {} is not valid hex", strip_nul(x)))),
+        Metadatum::Bytes(_) | Metadatum::Text(_) => {
+            Err(Cip25ParseError("invalid length for policy key".to_string()))
+        }
+        _ => Err(Cip25ParseError(
+            "invalid metadatum type for policy key".to_string(),
+        )),
     }
 }
 
-// Heuristic approach for filtering asset entries. Even less strict than when
-// searching for policies. In this case, we only check for valid data types.
-// There's probably a much more formal approach.
-fn is_asset_key(key: &Metadatum) -> Option<AssetName> {
+fn is_asset_key(key: &Metadatum) -> Result<AssetName, Cip25ParseError> {
     match key {
-        Metadatum::Bytes(x) if x.len() <= 32 => AssetName::try_from(x.as_slice()).ok(),
-        Metadatum::Text(x) if x.as_bytes().len() <= 32 => AssetName::try_from(x.as_bytes()).ok(),
-        _ => None,
+        Metadatum::Bytes(x) if x.len() <= 32 => Ok(x.to_vec()),
+        Metadatum::Text(x) if x.as_bytes().len() <= 32 => Ok(x.as_bytes().to_vec()),
+        Metadatum::Bytes(_) | Metadatum::Text(_) => {
+            Err(Cip25ParseError("invalid length for asset key".to_string()))
+        }
+        _ => Err(Cip25ParseError(
+            "invalid metadatum type for asset key".to_string(),
+        )),
     }
 }
 
 fn search_cip25_version(content_721: &Metadatum) -> Option<String> {
     match content_721 {
         Metadatum::Map(entries) => entries.iter().find_map(|(key, value)| match key {
-            Metadatum::Text(x) if x == "version" => match value {
-                Metadatum::Text(value) => Some(value.to_owned()),
+            Metadatum::Text(x) if x == VERSION_KEY => match value {
+                Metadatum::Text(value) => Some(strip_nul(value)),
+                // CBOR metadata has no floats so v2 is usually written as the integer 2
+                Metadatum::Int(value) => Some(format!("{}.0", i128::from(*value))),
                 _ => None,
             },
             _ => None,
@@ -42,15 +87,94 @@ fn search_cip25_version(content_721: &Metadatum) -> Option<String> {
     }
 }
 
+fn is_version_key(key: &Metadatum) -> bool {
+    matches!(key, Metadatum::Text(x) if x == VERSION_KEY)
+}
+
+fn find_field<'a>(entries: &'a [(Metadatum, Metadatum)], field: &str) -> Option<&'a Metadatum> {
+    entries.iter().find_map(|(key, value)| match key {
+        Metadatum::Text(x) if x == field => Some(value),
+        _ => None,
+    })
+}
+
+// strings longer than 64 bytes have to be split into an array of chunks
+fn to_string(value: &Metadatum) -> Result<String, Cip25ParseError> {
+    let text = match value {
+        Metadatum::Text(x) => Ok(x.clone()),
+        Metadatum::Array(chunks) => chunks
+            .iter()
+            .map(|chunk| match chunk {
+                Metadatum::Text(x) => Ok(x.as_str()),
+                _ => Err(Cip25ParseError(
+                    "invalid metadatum type for string chunk".to_string(),
+                )),
+            })
+            .collect::<Result<String, _>>(),
+        _ => Err(Cip25ParseError(
+            "invalid metadatum type for string".to_string(),
+        )),
+    }?;
+    Ok(strip_nul(&text))
+}
+
+fn get_cip25_fields(content: &Metadatum) -> Result<Cip25Fields, Cip25ParseError> {
+    let entries = match content {
+        Metadatum::Map(entries) => entries,
+        _ => {
+            return Err(Cip25ParseError(
+                "invalid metadatum type for asset inside 721 label".to_string(),
+            ))
+        }
+    };
+    // fields are optional so a field with an unexpected type is treated as missing
+    // instead of rejecting the whole asset
+    let string_field =
+        |field: &str| find_field(entries, field).and_then(|value| to_string(value).ok());
+
+    Ok(Cip25Fields {
+        name: string_field("name"),
+        image: string_field("image"),
+        media_type: string_field("mediaType"),
+        description: string_field("description"),
+        files: find_field(entries, "files").map(metadatum_to_json),
+    })
+}
+
 fn get_cip25_assets(
-    _version: &str,
+    policy_id: &PolicyId,
     content: &Metadatum,
-) -> Result<BTreeMap<AssetName, Payload>, Cip25ParseError> {
-    let mut result = BTreeMap::<AssetName, Payload>::default();
+    rejections: &mut Vec<Cip25Rejection>,
+) -> Result<BTreeMap<AssetName, Cip25Asset>, Cip25ParseError> {
+    let mut result = BTreeMap::<AssetName, Cip25Asset>::default();
     if let Metadatum::Map(entries) = content {
         for (key, sub_content) in entries.iter() {
-            if let Some(asset) = &is_asset_key(key) {
-                result.insert(asset.clone(), sub_content.encode_fragment().unwrap());
+            let asset_name = match is_asset_key(key) {
+                Ok(asset_name) => asset_name,
+                Err(reason) => {
+                    rejections.push(Cip25Rejection {
+                        policy_id: Some(policy_id.clone()),
+                        asset_name: None,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+            match get_cip25_fields(sub_content) {
+                Ok(fields) => {
+                    result.insert(
+                        asset_name,
+                        Cip25Asset {
+                            payload: sub_content.encode_fragment().unwrap(),
+                            fields,
+                        },
+                    );
+                }
+                Err(reason) => rejections.push(Cip25Rejection {
+                    policy_id: Some(policy_id.clone()),
+                    asset_name: Some(asset_name),
+                    reason,
+                }),
             }
         }
     } else {
@@ -62,19 +186,40 @@ fn get_cip25_assets(
     Ok(result)
 }
 
-#[allow(clippy::type_complexity)]
-pub fn get_cip25_pairs(
-    content: &Metadatum,
-) -> Result<(String, BTreeMap<PolicyId, BTreeMap<AssetName, Payload>>), Cip25ParseError> {
+/// Parses the content of the 721 metadata label
+/// Entries that can't be parsed are returned as rejections instead of failing the whole label
+pub fn get_cip25_pairs(content: &Metadatum) -> Result<Cip25Pairs, Cip25ParseError> {
     let version = search_cip25_version(content).unwrap_or_else(|| "1.0".to_string());
 
-    let mut result = BTreeMap::<PolicyId, BTreeMap<AssetName, Payload>>::default();
+    let mut result = Cip25Pairs {
+        version,
+        ..Default::default()
+    };
     if let Metadatum::Map(entries) = content {
         for (key, sub_content) in entries.iter() {
-            if let Some(policy_id) = is_policy_key(key) {
-                if let Ok(asset_names) = get_cip25_assets(&version, sub_content) {
-                    result.insert(policy_id, asset_names);
+            if is_version_key(key) {
+                continue;
+            }
+            let policy_id = match is_policy_key(key) {
+                Ok(policy_id) => policy_id,
+                Err(reason) => {
+                    result.rejections.push(Cip25Rejection {
+                        policy_id: None,
+                        asset_name: None,
+                        reason,
+                    });
+                    continue;
                 }
+            };
+            match get_cip25_assets(&policy_id, sub_content, &mut result.rejections) {
+                Ok(asset_names) => {
+                    result.assets.insert(policy_id, asset_names);
+                }
+                Err(reason) => result.rejections.push(Cip25Rejection {
+                    policy_id: Some(policy_id),
+                    asset_name: None,
+                    reason,
+                }),
             }
         }
     } else {
@@ -83,5 +228,125 @@ pub fn get_cip25_pairs(
         ));
     }
 
-    Ok((version, result))
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use pallas::codec::{minicbor::data::Int, utils::KeyValuePairs};
+
+    use super::*;
+
+    const POLICY: [u8; 28] = [0xab; 28];
+
+    fn text(x: &str) -> Metadatum {
+        Metadatum::Text(x.to_string())
+    }
+
+    fn int(x: i128) -> Metadatum {
+        Metadatum::Int(Int::try_from(x).unwrap())
+    }
+
+    fn bytes(x: &[u8]) -> Metadatum {
+        Metadatum::Bytes(x.to_vec().into())
+    }
+
+    fn map(entries: Vec<(Metadatum, Metadatum)>) -> Metadatum {
+        Metadatum::Map(KeyValuePairs::Def(entries))
+    }
+
+    #[test]
+    fn parses_v1_text_keys() {
+        let content = map(vec![(
+            text(&hex::encode(POLICY)),
+            map(vec![(
+                text("MyNFT"),
+                map(vec![
+                    (text("name"), text("My NFT")),
+                    (
+                        text("image"),
+                        Metadatum::Array(vec![text("ipfs://"), text("abc")]),
+                    ),
+                    (text("mediaType"), text("image/png")),
+                ]),
+            )]),
+        )]);
+
+        let pairs = get_cip25_pairs(&content).unwrap();
+        assert_eq!(pairs.version, "1.0");
+        assert!(pairs.rejections.is_empty());
+        let fields = &pairs.assets[&POLICY.to_vec()][&b"MyNFT".to_vec()].fields;
+        assert_eq!(fields.name.as_deref(), Some("My NFT"));
+        assert_eq!(fields.image.as_deref(), Some("ipfs://abc"));
+        assert_eq!(fields.media_type.as_deref(), Some("image/png"));
+        assert_eq!(fields.description, None);
+    }
+
+    #[test]
+    fn parses_v2_bytes_keys() {
+        let content = map(vec![
+            (
+                bytes(&POLICY),
+                map(vec![(
+                    bytes(&[0x01, 0x02]),
+                    map(vec![(text("name"), text("x"))]),
+                )]),
+            ),
+            (text("version"), int(2)),
+        ]);
+
+        let pairs = get_cip25_pairs(&content).unwrap();
+        assert_eq!(pairs.version, "2.0");
+        assert!(pairs.rejections.is_empty());
+        assert!(pairs.assets[&POLICY.to_vec()].contains_key(&vec![0x01, 0x02]));
+    }
+
+    #[test]
+    fn rejects_invalid_entries_without_failing_the_label() {
+        let content = map(vec![
+            (text("not a policy"), map(vec![])),
+            (
+                bytes(&POLICY),
+                map(vec![
+                    (bytes(&[0; 33]), map(vec![])),
+                    (text("invalid"), text("not a map")),
+                    (text("valid"), map(vec![])),
+                ]),
+            ),
+        ]);
+
+        let pairs = get_cip25_pairs(&content).unwrap();
+        assert_eq!(pairs.rejections.len(), 3);
+        assert_eq!(pairs.rejections[0].policy_id, None);
+        assert_eq!(pairs.rejections[1].policy_id, Some(POLICY.to_vec()));
+        assert_eq!(pairs.rejections[1].asset_name, None);
+        assert_eq!(pairs.rejections[2].asset_name, Some(b"invalid".to_vec()));
+        let assets = &pairs.assets[&POLICY.to_vec()];
+        assert_eq!(assets.len(), 1);
+        assert!(assets.contains_key(&b"valid".to_vec()));
+    }
+
+    #[test]
+    fn ignores_fields_with_unexpected_types() {
+        let content = map(vec![(
+            bytes(&POLICY),
+            map(vec![(
+                text("a"),
+                map(vec![
+                    (text("name"), int(5)),
+                    (text("description"), text("no\0nul")),
+                ]),
+            )]),
+        )]);
+
+        let pairs = get_cip25_pairs(&content).unwrap();
+        let fields = &pairs.assets[&POLICY.to_vec()][&b"a".to_vec()].fields;
+        assert_eq!(fields.name, None);
+        assert_eq!(fields.description.as_deref(), Some("nonul"));
+    }
+
+    #[test]
+    fn fails_if_the_label_is_not_a_map() {
+        assert!(get_cip25_pairs(&text("721")).is_err());
+    }
 }
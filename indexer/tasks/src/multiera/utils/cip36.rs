@@ -0,0 +1,196 @@
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-0036
+// also handles the older CIP-15 format where the voting key is a single key without a weight
+
+use cryptoxide::ed25519;
+use pallas::ledger::primitives::alonzo::Metadatum;
+
+use crate::utils::blake2b256;
+
+pub const REGISTRATION_LABEL: u64 = 61284;
+pub const SIGNATURE_LABEL: u64 = 61285;
+
+pub struct Cip36Registration {
+    pub delegations: Vec<(Vec<u8>, i64)>,
+    pub stake_key: [u8; 32],
+    pub reward_address: Vec<u8>,
+    pub nonce: i64,
+    pub voting_purpose: i64,
+}
+
+fn find_key(entries: &[(Metadatum, Metadatum)], key: i128) -> Option<&Metadatum> {
+    entries.iter().find_map(|(k, value)| match k {
+        Metadatum::Int(k) if i128::from(*k) == key => Some(value),
+        _ => None,
+    })
+}
+
+// note: integers are stored as BIGINT so the ones above i64::MAX make the registration invalid
+fn as_uint(value: &Metadatum) -> Option<i64> {
+    match value {
+        Metadatum::Int(x) => i64::try_from(i128::from(*x)).ok().filter(|x| *x >= 0),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: &Metadatum) -> Option<Vec<u8>> {
+    match value {
+        Metadatum::Bytes(x) => Some(x.to_vec()),
+        _ => None,
+    }
+}
+
+fn parse_delegations(value: &Metadatum) -> Option<Vec<(Vec<u8>, i64)>> {
+    match value {
+        // CIP-15: a single voting key that gets all the voting power
+        Metadatum::Bytes(key) => Some(vec![(key.to_vec(), 1)]),
+        Metadatum::Array(delegations) if !delegations.is_empty() => delegations
+            .iter()
+            .map(|delegation| match delegation {
+                Metadatum::Array(pair) if pair.len() == 2 => {
+                    Some((as_bytes(&pair[0])?, as_uint(&pair[1])?))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Parses the content of the 61284 label. Returns None if it is malformed
+pub fn parse_registration(content: &Metadatum) -> Option<Cip36Registration> {
+    let entries = match content {
+        Metadatum::Map(entries) => entries,
+        _ => return None,
+    };
+    Some(Cip36Registration {
+        delegations: parse_delegations(find_key(entries, 1)?)?,
+        stake_key: as_bytes(find_key(entries, 2)?)?.try_into().ok()?,
+        reward_address: as_bytes(find_key(entries, 3)?)?,
+        nonce: as_uint(find_key(entries, 4)?)?,
+        voting_purpose: match find_key(entries, 5) {
+            Some(purpose) => as_uint(purpose)?,
+            // 0 is the catalyst voting purpose
+            None => 0,
+        },
+    })
+}
+
+/// Checks the signature in the 61285 label against the raw 61284 label
+/// The signed message is the hash of the metadata map containing only the 61284 label
+pub fn verify_signature(
+    registration: &Cip36Registration,
+    registration_payload: &[u8],
+    signature_content: &Metadatum,
+) -> bool {
+    let signature: [u8; 64] = match signature_content {
+        Metadatum::Map(entries) => match find_key(entries, 1).and_then(as_bytes) {
+            Some(signature) => match signature.try_into() {
+                Ok(signature) => signature,
+                Err(_) => return false,
+            },
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    // CBOR header of a map with one entry followed by 61284 as an unsigned integer
+    let mut message = vec![0xa1, 0x19];
+    message.extend(&(REGISTRATION_LABEL as u16).to_be_bytes());
+    message.extend(registration_payload);
+
+    ed25519::verify(&blake2b256(&message), &registration.stake_key, &signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use pallas::codec::{minicbor::data::Int, utils::KeyValuePairs};
+    use pallas::ledger::primitives::Fragment;
+
+    use super::*;
+
+    fn int(x: u64) -> Metadatum {
+        Metadatum::Int(Int::from(x))
+    }
+
+    fn bytes(x: &[u8]) -> Metadatum {
+        Metadatum::Bytes(x.to_vec().into())
+    }
+
+    fn map(entries: Vec<(Metadatum, Metadatum)>) -> Metadatum {
+        Metadatum::Map(KeyValuePairs::Def(entries))
+    }
+
+    fn registration(delegations: Metadatum, stake_key: &[u8]) -> Metadatum {
+        map(vec![
+            (int(1), delegations),
+            (int(2), bytes(stake_key)),
+            (int(3), bytes(&[0xe0; 29])),
+            (int(4), int(42)),
+        ])
+    }
+
+    #[test]
+    fn parses_cip36_delegations() {
+        let delegations = Metadatum::Array(vec![
+            Metadatum::Array(vec![bytes(&[1; 32]), int(1)]),
+            Metadatum::Array(vec![bytes(&[2; 32]), int(3)]),
+        ]);
+
+        let parsed = parse_registration(&registration(delegations, &[0; 32])).unwrap();
+        assert_eq!(parsed.delegations, vec![(vec![1; 32], 1), (vec![2; 32], 3)]);
+        assert_eq!(parsed.reward_address, vec![0xe0; 29]);
+        assert_eq!(parsed.nonce, 42);
+        assert_eq!(parsed.voting_purpose, 0);
+    }
+
+    #[test]
+    fn parses_cip15_voting_key() {
+        let parsed = parse_registration(&registration(bytes(&[1; 32]), &[0; 32])).unwrap();
+        assert_eq!(parsed.delegations, vec![(vec![1; 32], 1)]);
+    }
+
+    #[test]
+    fn rejects_malformed_registrations() {
+        // the stake key has to be 32 bytes
+        assert!(parse_registration(&registration(bytes(&[1; 32]), &[0; 31])).is_none());
+        // delegations can't be empty
+        assert!(parse_registration(&registration(Metadatum::Array(vec![]), &[0; 32])).is_none());
+        assert!(parse_registration(&int(1)).is_none());
+    }
+
+    #[test]
+    fn rejects_integers_above_i64_max() {
+        let mut content = registration(bytes(&[1; 32]), &[0; 32]);
+        if let Metadatum::Map(KeyValuePairs::Def(entries)) = &mut content {
+            entries[3].1 = int(u64::MAX);
+        }
+        assert!(parse_registration(&content).is_none());
+
+        let delegations = Metadatum::Array(vec![Metadatum::Array(vec![
+            bytes(&[1; 32]),
+            int(i64::MAX as u64 + 1),
+        ])]);
+        assert!(parse_registration(&registration(delegations, &[0; 32])).is_none());
+    }
+
+    #[test]
+    fn verifies_the_signature() {
+        let (keypair, public_key) = ed25519::keypair(&[7; 32]);
+        let content = registration(bytes(&[1; 32]), &public_key);
+        let payload = content.encode_fragment().unwrap();
+
+        let mut message = vec![0xa1, 0x19];
+        message.extend(&(REGISTRATION_LABEL as u16).to_be_bytes());
+        message.extend(&payload);
+        let signature = ed25519::signature(&blake2b256(&message), &keypair);
+
+        let parsed = parse_registration(&content).unwrap();
+        let signature_content = map(vec![(int(1), bytes(&signature))]);
+        assert!(verify_signature(&parsed, &payload, &signature_content));
+
+        let mut wrong_signature = signature;
+        wrong_signature[0] ^= 1;
+        let signature_content = map(vec![(int(1), bytes(&wrong_signature))]);
+        assert!(!verify_signature(&parsed, &payload, &signature_content));
+    }
+}
@@ -0,0 +1,80 @@
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-0067
+// asset name labels are encoded as 0 | label (16 bits) | crc8 (8 bits) | 0
+pub const REFERENCE_NFT_LABEL: [u8; 4] = [0x00, 0x06, 0x43, 0xb0]; // (100)
+pub const NFT_LABEL: [u8; 4] = [0x00, 0x0d, 0xe1, 0x40]; // (222)
+pub const FT_LABEL: [u8; 4] = [0x00, 0x14, 0xdf, 0x10]; // (333)
+pub const RFT_LABEL: [u8; 4] = [0x00, 0x1b, 0xc2, 0x80]; // (444)
+
+pub fn is_reference_token(asset_name: &[u8]) -> bool {
+    asset_name.starts_with(&REFERENCE_NFT_LABEL)
+}
+
+/// Returns the CIP-67 label of a user token (222, 333 or 444) if the asset name has one
+pub fn user_token_label(asset_name: &[u8]) -> Option<i32> {
+    if asset_name.starts_with(&NFT_LABEL) {
+        Some(222)
+    } else if asset_name.starts_with(&FT_LABEL) {
+        Some(333)
+    } else if asset_name.starts_with(&RFT_LABEL) {
+        Some(444)
+    } else {
+        None
+    }
+}
+
+/// The reference token shares the policy and the asset name (after the label) of the user token
+pub fn reference_name_for(asset_name: &[u8]) -> Vec<u8> {
+    [
+        &REFERENCE_NFT_LABEL[..],
+        &asset_name[REFERENCE_NFT_LABEL.len()..],
+    ]
+    .concat()
+}
+
+/// The user tokens (with their label) that share the policy and the asset name of the reference token
+pub fn user_names_for(reference_name: &[u8]) -> [(i32, Vec<u8>); 3] {
+    [(222, NFT_LABEL), (333, FT_LABEL), (444, RFT_LABEL)].map(|(label, prefix)| {
+        (
+            label,
+            [&prefix[..], &reference_name[REFERENCE_NFT_LABEL.len()..]].concat(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_labels() {
+        let name = b"token";
+        assert!(is_reference_token(
+            &[&REFERENCE_NFT_LABEL[..], name].concat()
+        ));
+        assert!(!is_reference_token(&[&NFT_LABEL[..], name].concat()));
+        assert_eq!(
+            user_token_label(&[&NFT_LABEL[..], name].concat()),
+            Some(222)
+        );
+        assert_eq!(user_token_label(&[&FT_LABEL[..], name].concat()), Some(333));
+        assert_eq!(
+            user_token_label(&[&RFT_LABEL[..], name].concat()),
+            Some(444)
+        );
+        assert_eq!(
+            user_token_label(&[&REFERENCE_NFT_LABEL[..], name].concat()),
+            None
+        );
+        assert_eq!(user_token_label(name), None);
+    }
+
+    #[test]
+    fn pairs_user_and_reference_tokens() {
+        let name = b"token";
+        let reference = [&REFERENCE_NFT_LABEL[..], name].concat();
+        for (label, user) in user_names_for(&reference) {
+            assert_eq!(user_token_label(&user), Some(label));
+            assert_eq!(reference_name_for(&user), reference);
+        }
+    }
+}
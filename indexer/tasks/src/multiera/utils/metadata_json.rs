@@ -0,0 +1,155 @@
+use pallas::ledger::primitives::alonzo::Metadatum;
+
+/// Postgres doesn't allow NUL characters in TEXT columns nor in JSONB strings
+pub fn strip_nul(x: &str) -> String {
+    x.replace('\0', "")
+}
+
+// cardano-cli renders bytes as hex strings with a 0x prefix
+fn bytes_to_string(x: &[u8]) -> String {
+    format!("0x{}", hex::encode(x))
+}
+
+/// Maps a metadatum to JSON using the no schema mapping of cardano-cli
+/// Text is stripped of NUL characters so the result can be stored as JSONB
+pub fn metadatum_to_json(value: &Metadatum) -> serde_json::Value {
+    match value {
+        Metadatum::Int(x) => {
+            // metadata integers can go past the i64 range in both directions
+            let x = i128::from(*x);
+            if let Ok(x) = i64::try_from(x) {
+                serde_json::Value::from(x)
+            } else if let Ok(x) = u64::try_from(x) {
+                serde_json::Value::from(x)
+            } else {
+                serde_json::Value::from(x.to_string())
+            }
+        }
+        Metadatum::Bytes(x) => serde_json::Value::from(bytes_to_string(x)),
+        Metadatum::Text(x) => serde_json::Value::from(strip_nul(x)),
+        Metadatum::Array(x) => serde_json::Value::Array(x.iter().map(metadatum_to_json).collect()),
+        Metadatum::Map(x) => serde_json::Value::Object(
+            x.iter()
+                .map(|(key, value)| (metadatum_to_json_key(key), metadatum_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+// JSON keys have to be strings
+fn metadatum_to_json_key(key: &Metadatum) -> String {
+    match key {
+        Metadatum::Text(key) => strip_nul(key),
+        Metadatum::Int(key) => i128::from(*key).to_string(),
+        Metadatum::Bytes(key) => bytes_to_string(key),
+        // cardano-cli rejects these keys so there is no standard mapping for them
+        Metadatum::Array(_) | Metadatum::Map(_) => metadatum_to_json(key).to_string(),
+    }
+}
+
+// https://github.com/cardano-foundation/CIPs/tree/master/CIP-0020
+pub const CIP20_LABEL: u64 = 674;
+
+/// Extracts the message of a CIP-20 label with each line of the `msg` array separated by a newline
+pub fn get_cip20_message(content: &Metadatum) -> Option<String> {
+    let entries = match content {
+        Metadatum::Map(entries) => entries,
+        _ => return None,
+    };
+    let lines = entries.iter().find_map(|(key, value)| match (key, value) {
+        (Metadatum::Text(key), Metadatum::Array(lines)) if key == "msg" => Some(lines),
+        _ => None,
+    })?;
+    lines
+        .iter()
+        .map(|line| match line {
+            Metadatum::Text(line) => Some(line.as_str()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|lines| strip_nul(&lines.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use pallas::codec::{minicbor::data::Int, utils::KeyValuePairs};
+
+    use super::*;
+
+    fn text(x: &str) -> Metadatum {
+        Metadatum::Text(x.to_string())
+    }
+
+    fn int(x: i128) -> Metadatum {
+        Metadatum::Int(Int::try_from(x).unwrap())
+    }
+
+    fn bytes(x: &[u8]) -> Metadatum {
+        Metadatum::Bytes(x.to_vec().into())
+    }
+
+    fn map(entries: Vec<(Metadatum, Metadatum)>) -> Metadatum {
+        Metadatum::Map(KeyValuePairs::Def(entries))
+    }
+
+    #[test]
+    fn maps_integers() {
+        assert_eq!(metadatum_to_json(&int(-5)), serde_json::json!(-5));
+        assert_eq!(
+            metadatum_to_json(&int(u64::MAX as i128)),
+            serde_json::json!(u64::MAX)
+        );
+        assert_eq!(
+            metadatum_to_json(&int(-(1 << 64))),
+            serde_json::json!("-18446744073709551616")
+        );
+    }
+
+    #[test]
+    fn maps_bytes_and_text() {
+        assert_eq!(
+            metadatum_to_json(&bytes(&[0xde, 0xad])),
+            serde_json::json!("0xdead")
+        );
+        assert_eq!(metadatum_to_json(&text("a\0b")), serde_json::json!("ab"));
+    }
+
+    #[test]
+    fn maps_keys_to_strings() {
+        let value = map(vec![
+            (text("text"), int(1)),
+            (int(-2), int(2)),
+            (bytes(&[0xbe, 0xef]), int(3)),
+        ]);
+        assert_eq!(
+            metadatum_to_json(&value),
+            serde_json::json!({ "text": 1, "-2": 2, "0xbeef": 3 })
+        );
+    }
+
+    #[test]
+    fn maps_nested_lists() {
+        let value = Metadatum::Array(vec![
+            Metadatum::Array(vec![int(1), bytes(&[0x01])]),
+            map(vec![(text("list"), Metadatum::Array(vec![text("a")]))]),
+            Metadatum::Array(vec![]),
+        ]);
+        assert_eq!(
+            metadatum_to_json(&value),
+            serde_json::json!([[1, "0x01"], { "list": ["a"] }, []])
+        );
+    }
+
+    #[test]
+    fn extracts_cip20_messages() {
+        let content = map(vec![(
+            text("msg"),
+            Metadatum::Array(vec![text("line 1"), text("line\02")]),
+        )]);
+        assert_eq!(
+            get_cip20_message(&content).as_deref(),
+            Some("line 1\nline2")
+        );
+        assert_eq!(get_cip20_message(&map(vec![])), None);
+    }
+}
@@ -1,2 +1,5 @@
 pub mod cip25_parse;
+pub mod cip36;
+pub mod cip68;
+pub mod metadata_json;
 pub mod user_asset;
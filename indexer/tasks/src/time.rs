@@ -0,0 +1,107 @@
+/// Era summaries needed to convert a slot into a wallclock time or an epoch
+/// Note: the slot length only changed once (at the Byron -> Shelley hard fork) so two eras are enough
+/// All durations are in milliseconds since slot lengths aren't always a whole number of seconds
+#[derive(Debug, Clone, Copy)]
+pub struct TimeParams {
+    /// unix timestamp (in milliseconds) of slot 0
+    pub system_start: u64,
+    /// in milliseconds
+    pub byron_slot_length: u64,
+    /// number of slots in a Byron epoch
+    pub byron_epoch_length: u64,
+    /// first slot of the Shelley era (0 for networks that never had Byron blocks)
+    pub shelley_start_slot: u64,
+    /// in milliseconds
+    pub shelley_slot_length: u64,
+    /// number of slots in a Shelley (or later) epoch
+    pub shelley_epoch_length: u64,
+}
+
+impl TimeParams {
+    /// Returns the unix timestamp (in milliseconds) at which the slot starts
+    pub fn slot_to_time(&self, slot: u64) -> u64 {
+        if slot < self.shelley_start_slot {
+            self.system_start + slot * self.byron_slot_length
+        } else {
+            self.system_start
+                + self.shelley_start_slot * self.byron_slot_length
+                + (slot - self.shelley_start_slot) * self.shelley_slot_length
+        }
+    }
+
+    /// Returns the epoch of the slot and the index of the slot within that epoch
+    pub fn slot_to_epoch(&self, slot: u64) -> (u64, u64) {
+        if slot < self.shelley_start_slot {
+            return (
+                slot / self.byron_epoch_length,
+                slot % self.byron_epoch_length,
+            );
+        }
+        let shelley_start_epoch = self.shelley_start_slot / self.byron_epoch_length;
+        let shelley_slot = slot - self.shelley_start_slot;
+        (
+            shelley_start_epoch + shelley_slot / self.shelley_epoch_length,
+            shelley_slot % self.shelley_epoch_length,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAINNET: TimeParams = TimeParams {
+        system_start: 1506203091000,
+        byron_slot_length: 20000,
+        byron_epoch_length: 21600,
+        shelley_start_slot: 4492800,
+        shelley_slot_length: 1000,
+        shelley_epoch_length: 432000,
+    };
+
+    // networks that start in Shelley have no Byron slots
+    const PREVIEW: TimeParams = TimeParams {
+        system_start: 1666656000000,
+        byron_slot_length: 20000,
+        byron_epoch_length: 4320,
+        shelley_start_slot: 0,
+        shelley_slot_length: 1000,
+        shelley_epoch_length: 86400,
+    };
+
+    #[test]
+    fn converts_byron_slots() {
+        assert_eq!(MAINNET.slot_to_time(0), 1506203091000);
+        assert_eq!(MAINNET.slot_to_time(1), 1506203111000);
+        assert_eq!(MAINNET.slot_to_epoch(0), (0, 0));
+        assert_eq!(MAINNET.slot_to_epoch(4492799), (207, 21599));
+    }
+
+    #[test]
+    fn converts_shelley_slots() {
+        // first block of Shelley on mainnet (2020-07-29T21:44:51Z)
+        assert_eq!(MAINNET.slot_to_time(4492800), 1596059091000);
+        assert_eq!(MAINNET.slot_to_epoch(4492800), (208, 0));
+        assert_eq!(MAINNET.slot_to_time(4924800), 1596491091000);
+        assert_eq!(MAINNET.slot_to_epoch(4924801), (209, 1));
+    }
+
+    #[test]
+    fn converts_slots_without_byron() {
+        assert_eq!(PREVIEW.slot_to_time(0), 1666656000000);
+        assert_eq!(PREVIEW.slot_to_time(86401), 1666742401000);
+        assert_eq!(PREVIEW.slot_to_epoch(86399), (0, 86399));
+        assert_eq!(PREVIEW.slot_to_epoch(86401), (1, 1));
+    }
+
+    #[test]
+    fn converts_sub_second_slots() {
+        let params = TimeParams {
+            shelley_slot_length: 100,
+            shelley_epoch_length: 500,
+            ..PREVIEW
+        };
+        assert_eq!(params.slot_to_time(15), 1666656001500);
+        assert_eq!(params.slot_to_epoch(1234), (2, 234));
+    }
+}